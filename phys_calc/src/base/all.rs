@@ -1,4 +1 @@
-use super::unit_traits::*;
-use crate::math::{number, EqualsOrZero};
-
 pub use crate::Unit;
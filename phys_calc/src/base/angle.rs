@@ -1,11 +1,7 @@
-use std::fmt::Display;
-use std::fmt::LowerExp;
 use std::marker::PhantomData;
 
-use phys_calc_macros::impl_derived_conversions;
-use phys_calc_macros::Unit;
-
-trait Pi {
+// `number::pi()`, whichever float `number` is.
+pub(crate) trait Pi {
     fn pi() -> Self;
 }
 
@@ -22,102 +18,265 @@ impl Pi for f64 {
 }
 
 use super::unit_traits::*;
+use crate::compound::{impl_compound, parse_compound, Compound, CompoundParseError, UnitChain};
+use crate::dimension::dimension;
 use crate::math::*;
 use crate::math_helpers::*;
-use crate::math_impl::impl_math;
-use crate::specialization::False;
-use crate::specialization::Specialized;
-use crate::unit::None;
-use crate::unspecialize;
+use crate::unit::{None, Symbols};
 use crate::Unit;
 
-pub trait AngleUnit: Unit {
-    fn to_base(s: Angle<Self>) -> Angle<Radian> {
-        Angle {
-            inner: s.inner * Self::mult(),
-            types: PhantomData,
+dimension! {
+    Angle<A: AngleUnit>,
+    slot: Angle,
+    base: Radian("rad", latex = r"\radian", name = "radian"),
+    reference: Radian,
+}
+
+impl_compound!(Angle<A: AngleUnit>, Radian);
+
+impl<A: AngleUnit> Angle<A> {
+    fn radians(self) -> number {
+        A::to_base(self).inner
+    }
+
+    pub fn sin(self) -> number {
+        self.radians().sin()
+    }
+
+    pub fn cos(self) -> number {
+        self.radians().cos()
+    }
+
+    pub fn tan(self) -> number {
+        self.radians().tan()
+    }
+
+    pub fn sin_cos(self) -> (number, number) {
+        self.radians().sin_cos()
+    }
+
+    pub fn sinh(self) -> number {
+        self.radians().sinh()
+    }
+
+    pub fn cosh(self) -> number {
+        self.radians().cosh()
+    }
+
+    pub fn tanh(self) -> number {
+        self.radians().tanh()
+    }
+}
+
+// Directions: headings and bearings wrap around, 350° + 20° is 10°. All of
+// these keep the unit of `self`.
+impl<A: AngleUnit> Angle<A> {
+    // A full turn in `A`. The division leaves 400.00000000000006 gradians,
+    // snap to whole numbers so wrapping stays exact.
+    fn turn() -> number {
+        let turn = 2. * number::pi() / A::mult();
+        if (turn - turn.round()).abs() < 1e-9 * turn {
+            turn.round()
+        } else {
+            turn
         }
     }
-    fn from_base(s: Angle<Radian>) -> Angle<Self> {
-        Angle {
-            inner: s.inner / Self::mult(),
-            types: PhantomData,
+
+    // Into [0, 360°).
+    pub fn normalized(self) -> Self {
+        Self::new(self.inner.rem_euclid(Self::turn()))
+    }
+
+    // Into (-180°, 180°].
+    pub fn normalized_signed(self) -> Self {
+        let turn = Self::turn();
+        let wrapped = self.inner.rem_euclid(turn);
+        Self::new(if wrapped > turn / 2. {
+            wrapped - turn
+        } else {
+            wrapped
+        })
+    }
+
+    // The signed shortest rotation from `self` to `other`, positive counter
+    // clockwise, e.g. from 350° to 10° is 20°.
+    pub fn difference<B: AngleUnit>(self, other: Angle<B>) -> Self {
+        (other.convert::<A>() - self).normalized_signed()
+    }
+
+    // `t` of the way from `self` to `other` along the shorter arc.
+    pub fn lerp<B: AngleUnit>(self, other: Angle<B>, t: number) -> Self {
+        (self + self.difference(other) * t).normalized()
+    }
+
+    // The mean direction, 10° for 350° and 30°. `None` if there are no angles
+    // or they cancel out, like 0° and 180°.
+    pub fn circular_mean(angles: impl IntoIterator<Item = Self>) -> Option<Self> {
+        let (sin, cos) = angles
+            .into_iter()
+            .map(Self::sin_cos)
+            .fold((0., 0.), |(s, c), (sin, cos)| (s + sin, c + cos));
+        if sin.hypot(cos) < 1e-9 {
+            return Option::None;
         }
+        Some(Angle::atan2(sin, cos).convert::<A>().normalized())
     }
-    fn mult() -> number;
-    fn unit() -> &'static str;
 }
 
-impl_math!(Angle<A: AngleUnit>);
+// The inverses give radians, `.convert()` the result for other units.
+impl Angle<Radian> {
+    pub fn asin(value: number) -> Self {
+        Self::new(value.asin())
+    }
+
+    pub fn acos(value: number) -> Self {
+        Self::new(value.acos())
+    }
+
+    pub fn atan(value: number) -> Self {
+        Self::new(value.atan())
+    }
+
+    // The angle of the point (x, y), in (-π, π].
+    pub fn atan2(y: number, x: number) -> Self {
+        Self::new(y.atan2(x))
+    }
+
+    pub fn asinh(value: number) -> Self {
+        Self::new(value.asinh())
+    }
+
+    pub fn acosh(value: number) -> Self {
+        Self::new(value.acosh())
+    }
 
-#[derive(Copy, Clone, Debug)]
-pub struct Angle<A: AngleUnit> {
-    pub inner: number,
-    pub types: PhantomData<A>,
+    pub fn atanh(value: number) -> Self {
+        Self::new(value.atanh())
+    }
+}
+
+// Right ascension is written `5h 35m 17.3s`, or `5ʰ35ᵐ17.3ˢ`. The units' own
+// ASCII forms can't be `h`, `m` and `s`, those literals are hours, meters and
+// seconds, so they are swapped in here.
+fn hour_angle_links() -> Vec<(number, Symbols)> {
+    <(HourAngle, MinuteOfTime, SecondOfTime) as UnitChain<Radian>>::links()
+        .into_iter()
+        .zip(["h", "m", "s"])
+        .map(|((mult, symbols), ascii)| (mult, Symbols { ascii, ..symbols }))
+        .collect()
 }
 
+const COMPASS_POINTS: [&str; 32] = [
+    "N", "NbE", "NNE", "NEbN", "NE", "NEbE", "ENE", "EbN", "E", "EbS", "ESE", "SEbE", "SE", "SEbS",
+    "SSE", "SbE", "S", "SbW", "SSW", "SWbS", "SW", "SWbW", "WSW", "WbS", "W", "WbN", "WNW", "NWbW",
+    "NW", "NWbN", "NNW", "NbW",
+];
+
 impl<A: AngleUnit> Angle<A> {
-    pub fn convert<D: AngleUnit>(self) -> Angle<D> {
-        D::from_base(A::to_base(self))
+    pub fn hour_angle(&self) -> Compound {
+        Compound::new(self.value_in::<Radian>(), hour_angle_links())
     }
-}
 
-impl<A> Display for Angle<A>
-where
-    A: AngleUnit,
-{
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}{}", self.inner, A::unit())
+    pub fn parse_hour_angle(s: &str) -> Result<Self, CompoundParseError> {
+        let radians = parse_compound(s, &hour_angle_links())?;
+        Ok(Angle::<Radian>::new(radians).convert())
     }
-}
 
-impl<A> LowerExp for Angle<A>
-where
-    A: AngleUnit,
-{
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{:e}{}", self.inner, A::unit())
+    // The nearest of the 32 points of the compass, `NbE` is north by east.
+    pub fn compass_point(&self) -> &'static str {
+        let point = self.value_in::<CompassPoint>().round() as i64;
+        COMPASS_POINTS[point.rem_euclid(32) as usize]
     }
 }
 
 #[derive(Copy, Clone, Debug, Unit)]
 #[multiplier(number::pi() / 180. / 60. / 60.)]
-#[unit("″")]
+#[unit("″", ascii = "arcsec", latex = r"\arcsecond", name = "arcsecond")]
 #[unit_impl(AngleUnit)]
 pub struct Arcsecond;
 
 #[derive(Copy, Clone, Debug, Unit)]
 #[multiplier(number::pi() / 180. / 60.)]
-#[unit("′")]
+#[unit("′", ascii = "arcmin", latex = r"\arcminute", name = "arcminute")]
 #[unit_impl(AngleUnit)]
 pub struct Arcminute;
 
 #[derive(Copy, Clone, Debug, Unit)]
 #[multiplier(number::pi() / 180.)]
-#[unit("°")]
+#[unit("°", ascii = "deg", latex = r"\degree", name = "degree")]
 #[unit_impl(AngleUnit)]
 pub struct Degree;
 
 #[derive(Copy, Clone, Debug, Unit)]
 #[multiplier(1e-3)]
-#[unit("mrad")]
+#[unit("mrad", latex = r"\milli\radian", name = "milliradian")]
 #[unit_impl(AngleUnit)]
 pub struct MilliRadian;
 
 #[derive(Copy, Clone, Debug, Unit)]
-#[multiplier(1)]
-#[unit("rad")]
+#[multiplier(number::pi() / 200.)]
+#[unit("grad", latex = r"\text{grad}", name = "gradian")]
+#[unit_impl(AngleUnit)]
+pub struct Gradian;
+
+#[derive(Copy, Clone, Debug, Unit)]
+#[multiplier(2. * number::pi())]
+#[unit("tr", latex = r"\text{tr}", name = "turn")]
 #[unit_impl(AngleUnit)]
-pub struct Radian;
+pub struct Turn;
 
+pub type Revolution = Turn;
+
+// 6400 to the turn, as used by NATO.
 #[derive(Copy, Clone, Debug, Unit)]
-#[multiplier(number::pi() / 200.)]
-#[unit("grad")]
+#[multiplier(number::pi() / 3200.)]
+#[unit("mil", latex = r"\text{mil}", name = "mil")]
 #[unit_impl(AngleUnit)]
-pub struct Gradian;
+pub struct NatoMil;
+
+// 32 to the turn, 11.25°.
+#[derive(Copy, Clone, Debug, Unit)]
+#[multiplier(number::pi() / 16.)]
+#[unit("pt", latex = r"\text{pt}", name = "compass point")]
+#[unit_impl(AngleUnit)]
+pub struct CompassPoint;
 
-unspecialize!(Angle<A: AngleUnit>);
-impl_derived_conversions!(Angle<A: AngleUnit>, Angle: One, A);
+// Hour angles turn with the sky, 15° per hour and 15′ per minute of time.
+#[derive(Copy, Clone, Debug, Unit)]
+#[multiplier(number::pi() / 12.)]
+#[unit(
+    "ʰ",
+    ascii = "HA",
+    latex = r"\hour",
+    name = "hour angle",
+    plural = "hour angles"
+)]
+#[unit_impl(AngleUnit)]
+pub struct HourAngle;
+
+#[derive(Copy, Clone, Debug, Unit)]
+#[multiplier(number::pi() / 12. / 60.)]
+#[unit(
+    "ᵐ",
+    ascii = "HAm",
+    latex = r"\minute",
+    name = "minute of time",
+    plural = "minutes of time"
+)]
+#[unit_impl(AngleUnit)]
+pub struct MinuteOfTime;
+
+#[derive(Copy, Clone, Debug, Unit)]
+#[multiplier(number::pi() / 12. / 60. / 60.)]
+#[unit(
+    "ˢ",
+    ascii = "HAs",
+    latex = r"\second",
+    name = "second of time",
+    plural = "seconds of time"
+)]
+#[unit_impl(AngleUnit)]
+pub struct SecondOfTime;
 
 // impl<
 //         T: TimeUnit,
@@ -1,221 +1,196 @@
-use std::fmt::Display;
-use std::fmt::LowerExp;
 use std::marker::PhantomData;
 
-use phys_calc_macros::impl_derived_conversions;
-use phys_calc_macros::Unit;
-
 use super::unit_traits::*;
+use crate::dimension::dimension;
 use crate::math::*;
 use crate::math_helpers::*;
-use crate::math_impl::impl_math;
 use crate::unit::None;
-use crate::unspecialize;
 use crate::Unit;
 
-pub trait DigitalInformationUnit: Unit {
-    fn to_base(s: DigitalInformation<Self>) -> DigitalInformation<Byte> {
-        DigitalInformation {
-            inner: s.inner * Self::mult(),
-            types: PhantomData,
-        }
-    }
-    fn from_base(s: DigitalInformation<Byte>) -> DigitalInformation<Self> {
-        DigitalInformation {
-            inner: s.inner / Self::mult(),
-            types: PhantomData,
-        }
-    }
-    fn unit() -> &'static str;
-    fn mult() -> number;
-}
-impl_math!(DigitalInformation<D: DigitalInformationUnit>);
-
-#[derive(Copy, Clone, Debug)]
-pub struct DigitalInformation<D: DigitalInformationUnit> {
-    pub inner: number,
-    pub types: PhantomData<D>,
+dimension! {
+    DigitalInformation<D: DigitalInformationUnit>,
+    slot: DigitalInformation,
+    base: Bit("bit", latex = r"\bit", name = "bit"),
+    reference: Byte,
 }
 
 impl<R: DigitalInformationUnit> DigitalInformation<R> {
-    pub fn convert<D: DigitalInformationUnit>(self) -> DigitalInformation<D> {
-        D::from_base(R::to_base(self))
-    }
-}
-
-impl<R> Display for DigitalInformation<R>
-where
-    R: DigitalInformationUnit,
-{
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}{}", self.inner, R::unit())
-    }
-}
-
-impl<R> LowerExp for DigitalInformation<R>
-where
-    R: DigitalInformationUnit,
-{
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{:e}{}", self.inner, R::unit())
+    pub fn engineering_binary(&self) -> crate::format::Engineering {
+        self.engineering().binary()
     }
 }
 
-#[derive(Copy, Clone, Debug, Unit)]
-#[multiplier(1)]
-#[unit("bit")]
-#[unit_impl(DigitalInformationUnit)]
-pub struct Bit;
-
 #[derive(Copy, Clone, Debug, Unit)]
 #[multiplier(8)]
-#[unit("B")]
+#[unit("B", latex = r"\byte", name = "byte")]
 #[unit_impl(DigitalInformationUnit)]
 pub struct Byte;
 
 #[derive(Copy, Clone, Debug, Unit)]
 #[multiplier(1000)]
-#[unit("Kb")]
+#[unit("Kb", latex = r"\kilo\bit", name = "kilobit")]
 #[unit_impl(DigitalInformationUnit)]
 pub struct Kilobit;
 
 #[derive(Copy, Clone, Debug, Unit)]
 #[multiplier(1024)]
-#[unit("Kib")]
+#[unit("Kib", latex = r"\kibi\bit", name = "kibibit")]
 #[unit_impl(DigitalInformationUnit)]
 pub struct Kibibit;
 
 #[derive(Copy, Clone, Debug, Unit)]
 #[multiplier(8*1000)]
-#[unit("KB")]
+#[unit("KB", latex = r"\kilo\byte", name = "kilobyte")]
 #[unit_impl(DigitalInformationUnit)]
 pub struct Kilobyte;
 
 #[derive(Copy, Clone, Debug, Unit)]
 #[multiplier(8*1024)]
-#[unit("KiB")]
+#[unit("KiB", latex = r"\kibi\byte", name = "kibibyte")]
 #[unit_impl(DigitalInformationUnit)]
 pub struct Kibibyte;
 
 #[derive(Copy, Clone, Debug, Unit)]
 #[multiplier(1000*1000)]
-#[unit("Mb")]
+#[unit("Mb", latex = r"\mega\bit", name = "megabit")]
 #[unit_impl(DigitalInformationUnit)]
 pub struct Megabit;
 
 #[derive(Copy, Clone, Debug, Unit)]
 #[multiplier(1024*1024)]
-#[unit("Mib")]
+#[unit("Mib", latex = r"\mebi\bit", name = "mebibit")]
 #[unit_impl(DigitalInformationUnit)]
 pub struct Mebibit;
 
 #[derive(Copy, Clone, Debug, Unit)]
 #[multiplier(8*1000*1000)]
-#[unit("MB")]
+#[unit("MB", latex = r"\mega\byte", name = "megabyte")]
 #[unit_impl(DigitalInformationUnit)]
 pub struct Megabyte;
 
 #[derive(Copy, Clone, Debug, Unit)]
 #[multiplier(8*1024*1024)]
-#[unit("MiB")]
+#[unit("MiB", latex = r"\mebi\byte", name = "mebibyte")]
 #[unit_impl(DigitalInformationUnit)]
 pub struct Mebibyte;
 
 #[derive(Copy, Clone, Debug, Unit)]
 #[multiplier(1000*1000*1000)]
-#[unit("Gb")]
+#[unit("Gb", latex = r"\giga\bit", name = "gigabit")]
 #[unit_impl(DigitalInformationUnit)]
 pub struct Gigabit;
 
 #[derive(Copy, Clone, Debug, Unit)]
 #[multiplier(1024*1024*1024)]
-#[unit("Gib")]
+#[unit("Gib", latex = r"\gibi\bit", name = "gibibit")]
 #[unit_impl(DigitalInformationUnit)]
 pub struct Gibibit;
 
 #[derive(Copy, Clone, Debug, Unit)]
 #[multiplier(8*1000*1000*1000u64)]
-#[unit("GB")]
+#[unit("GB", latex = r"\giga\byte", name = "gigabyte")]
 #[unit_impl(DigitalInformationUnit)]
 pub struct Gigabyte;
 
 #[derive(Copy, Clone, Debug, Unit)]
 #[multiplier(8*1024*1024*1024u64)]
-#[unit("GiB")]
+#[unit("GiB", latex = r"\gibi\byte", name = "gibibyte")]
 #[unit_impl(DigitalInformationUnit)]
 pub struct Gibibyte;
 
 #[derive(Copy, Clone, Debug, Unit)]
 #[multiplier(1000*1000*1000*1000u64)]
-#[unit("Tb")]
+#[unit("Tb", latex = r"\tera\bit", name = "terabit")]
 #[unit_impl(DigitalInformationUnit)]
 pub struct Terabit;
 
 #[derive(Copy, Clone, Debug, Unit)]
 #[multiplier(1024*1024*1024*1024u64)]
-#[unit("Tib")]
+#[unit("Tib", latex = r"\tebi\bit", name = "tebibit")]
 #[unit_impl(DigitalInformationUnit)]
 pub struct Tebibit;
 
 #[derive(Copy, Clone, Debug, Unit)]
 #[multiplier(8*1000*1000*1000*1000u64)]
-#[unit("TB")]
+#[unit("TB", latex = r"\tera\byte", name = "terabyte")]
 #[unit_impl(DigitalInformationUnit)]
 pub struct Terabyte;
 
 #[derive(Copy, Clone, Debug, Unit)]
 #[multiplier(8*1024*1024*1024*1024u64)]
-#[unit("TiB")]
+#[unit("TiB", latex = r"\tebi\byte", name = "tebibyte")]
 #[unit_impl(DigitalInformationUnit)]
 pub struct Tebibyte;
 
 #[derive(Copy, Clone, Debug, Unit)]
 #[multiplier(1000*1000*1000*1000*1000u64)]
-#[unit("Pb")]
+#[unit("Pb", latex = r"\peta\bit", name = "petabit")]
 #[unit_impl(DigitalInformationUnit)]
 pub struct Petabit;
 
 #[derive(Copy, Clone, Debug, Unit)]
 #[multiplier(1024*1024*1024*1024*1024u64)]
-#[unit("Pib")]
+#[unit("Pib", latex = r"\pebi\bit", name = "pebibit")]
 #[unit_impl(DigitalInformationUnit)]
 pub struct Pebibit;
 
 #[derive(Copy, Clone, Debug, Unit)]
 #[multiplier(8*1000*1000*1000*1000*1000u64)]
-#[unit("PB")]
+#[unit("PB", latex = r"\peta\byte", name = "petabyte")]
 #[unit_impl(DigitalInformationUnit)]
 pub struct Petabyte;
 
 #[derive(Copy, Clone, Debug, Unit)]
 #[multiplier(8*1024*1024*1024*1024*1024u64)]
-#[unit("PiB")]
+#[unit("PiB", latex = r"\pebi\byte", name = "pebibyte")]
 #[unit_impl(DigitalInformationUnit)]
 pub struct Pebibyte;
 
 #[derive(Copy, Clone, Debug, Unit)]
 #[multiplier(1000*1000*1000*1000*1000*1000u64)]
-#[unit("Eb")]
+#[unit("Eb", latex = r"\exa\bit", name = "exabit")]
 #[unit_impl(DigitalInformationUnit)]
 pub struct Exabit;
 
 #[derive(Copy, Clone, Debug, Unit)]
 #[multiplier(1024*1024*1024*1024*1024*1024u64)]
-#[unit("Eib")]
+#[unit("Eib", latex = r"\exbi\bit", name = "exbibit")]
 #[unit_impl(DigitalInformationUnit)]
 pub struct Exbibit;
 
 #[derive(Copy, Clone, Debug, Unit)]
 #[multiplier(8*1000*1000*1000*1000*1000*1000u64)]
-#[unit("EB")]
+#[unit("EB", latex = r"\exa\byte", name = "exabyte")]
 #[unit_impl(DigitalInformationUnit)]
 pub struct Exabyte;
 
 #[derive(Copy, Clone, Debug, Unit)]
 #[multiplier(8*1024*1024*1024*1024*1024*1024u64)]
-#[unit("EiB")]
+#[unit("EiB", latex = r"\exbi\byte", name = "exbibyte")]
 #[unit_impl(DigitalInformationUnit)]
 pub struct Exbibyte;
 
-unspecialize!(DigitalInformation<D: DigitalInformationUnit>);
-impl_derived_conversions!(DigitalInformation<D: DigitalInformationUnit>, DigitalInformation: One, D);
+#[derive(Copy, Clone, Debug, Unit)]
+#[multiplier(4)]
+#[unit("nibble", latex = r"\text{nibble}", name = "nibble")]
+#[unit_impl(DigitalInformationUnit)]
+pub struct Nibble;
+
+// Word sizes as x86 names them, a word being 16 bits.
+#[derive(Copy, Clone, Debug, Unit)]
+#[multiplier(16)]
+#[unit("word", latex = r"\text{word}", name = "word")]
+#[unit_impl(DigitalInformationUnit)]
+pub struct Word;
+
+#[derive(Copy, Clone, Debug, Unit)]
+#[multiplier(32)]
+#[unit("dword", latex = r"\text{dword}", name = "double word")]
+#[unit_impl(DigitalInformationUnit)]
+pub struct DoubleWord;
+
+#[derive(Copy, Clone, Debug, Unit)]
+#[multiplier(64)]
+#[unit("qword", latex = r"\text{qword}", name = "quad word")]
+#[unit_impl(DigitalInformationUnit)]
+pub struct QuadWord;
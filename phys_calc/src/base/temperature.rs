@@ -1,83 +1,57 @@
 use std::fmt::Display;
-use std::fmt::LowerExp;
 use std::marker::PhantomData;
 
-use phys_calc_macros::impl_derived_conversions;
-
 use super::unit_traits::*;
+use crate::dimension::dimension;
 use crate::math::*;
 use crate::math_helpers::*;
-use crate::math_impl::impl_math;
 use crate::unit::None;
-use crate::unspecialize;
+use crate::unit::Symbols;
 use crate::Unit;
 
-pub trait TempUnit: Unit {
-    fn to_base(s: Temperature<Self>) -> Temperature<Kelvin>;
-    fn from_base(s: Temperature<Kelvin>) -> Temperature<Self>;
-    fn unit() -> &'static str;
-    fn mult() -> number;
-}
-
-impl_math!(Temperature<M: TempUnit>);
-
-#[derive(Copy, Clone, Debug)]
-pub struct Temperature<M: TempUnit> {
-    pub inner: number,
-    pub types: PhantomData<M>,
+dimension! {
+    Temperature<TMP: TempUnit>,
+    slot: Temp,
+    base: Kelvin("K", latex = r"\kelvin", name = "kelvin"),
+    reference: Kelvin,
 }
 
-impl<M: TempUnit> Temperature<M> {
-    pub fn convert<D: TempUnit>(self) -> Temperature<D> {
-        D::from_base(M::to_base(self))
+impl<TMP: TempUnit> Temperature<TMP> {
+    // Rejects temperatures below absolute zero. Nothing else does: `new` stays
+    // `const`, and `-` and negation give differences, which can be negative
+    // in any unit.
+    pub fn try_new(value: number) -> Result<Self, BelowAbsoluteZero> {
+        let temperature = Self::new(value);
+        match temperature.value_in::<Kelvin>() < 0. {
+            true => Err(BelowAbsoluteZero),
+            false => Ok(temperature),
+        }
     }
 }
 
-impl<M> Display for Temperature<M>
-where
-    M: TempUnit,
-{
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}{}", self.inner, M::unit())
-    }
-}
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct BelowAbsoluteZero;
 
-impl<M> LowerExp for Temperature<M>
-where
-    M: TempUnit,
-{
+impl Display for BelowAbsoluteZero {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{:e}{}", self.inner, M::unit())
+        write!(f, "temperature is below absolute zero")
     }
 }
 
-#[derive(Copy, Clone, Debug)]
-pub struct Kelvin;
+impl std::error::Error for BelowAbsoluteZero {}
 
-impl Unit for Kelvin {}
-impl TempUnit for Kelvin {
-    fn to_base(s: Temperature<Self>) -> Temperature<Kelvin> {
-        s
-    }
-
-    fn from_base(s: Temperature<Kelvin>) -> Temperature<Self> {
-        s
-    }
-
-    fn unit() -> &'static str {
-        "K"
-    }
-    fn mult() -> number {
-        1.
-    }
-}
 #[derive(Copy, Clone, Debug)]
 pub struct Celsius;
 
 impl Unit for Celsius {}
+impl EqualsOrZero for Celsius {
+    type SelfType = Self;
+}
+impl EqualsOrZero<None> for Celsius {
+    type SelfType = Self;
+}
 impl TempUnit for Celsius {
     fn to_base(s: Temperature<Self>) -> Temperature<Kelvin> {
-        // No need to check, due to invariant for Temperature.
         Temperature {
             inner: s.inner + 273.15,
             types: PhantomData,
@@ -85,7 +59,6 @@ impl TempUnit for Celsius {
     }
 
     fn from_base(s: Temperature<Kelvin>) -> Temperature<Self> {
-        // No need to check, due to invariant for Temperature.
         Temperature {
             inner: s.inner - 273.15,
             types: PhantomData,
@@ -96,6 +69,16 @@ impl TempUnit for Celsius {
         "°C"
     }
 
+    fn symbols() -> Symbols {
+        Symbols {
+            unicode: "°C",
+            ascii: "degC",
+            latex: r"\degreeCelsius",
+            singular: "degree Celsius",
+            plural: "degrees Celsius",
+        }
+    }
+
     fn mult() -> number {
         1.
     }
@@ -104,9 +87,14 @@ impl TempUnit for Celsius {
 pub struct Fahrenheit;
 
 impl Unit for Fahrenheit {}
+impl EqualsOrZero for Fahrenheit {
+    type SelfType = Self;
+}
+impl EqualsOrZero<None> for Fahrenheit {
+    type SelfType = Self;
+}
 impl TempUnit for Fahrenheit {
     fn to_base(s: Temperature<Self>) -> Temperature<Kelvin> {
-        // No need to check, due to invariant for Temperature.
         Temperature {
             inner: (s.inner - 32.) * (5. / 9.) + 273.15,
             types: PhantomData,
@@ -123,10 +111,69 @@ impl TempUnit for Fahrenheit {
     fn unit() -> &'static str {
         "°F"
     }
+
+    fn symbols() -> Symbols {
+        Symbols {
+            unicode: "°F",
+            ascii: "degF",
+            latex: r"\degree\text{F}",
+            singular: "degree Fahrenheit",
+            plural: "degrees Fahrenheit",
+        }
+    }
     fn mult() -> number {
         1.8
     }
 }
 
-unspecialize!(Temperature<TMP: TempUnit>);
-impl_derived_conversions!(Temperature<TMP: TempUnit>, Temp: One, TMP);
+// Celsius and Fahrenheit are written by hand, so their literal traits are too,
+// including the hidden per-symbol methods `q!` calls.
+macro_rules! temp_literal {
+    ($unit:ident, $trait_name:ident, [$($method:ident),+], hidden: [$($hidden:ident),+]) => {
+        #[allow(non_snake_case)]
+        pub trait $trait_name {
+            $(fn $method(self) -> Temperature<$unit>;)+
+            $(
+                #[doc(hidden)]
+                fn $hidden(self) -> Temperature<$unit>;
+            )+
+        }
+
+        macro_rules! temp_literal_impl {
+            ($typ:ty) => {
+                #[allow(non_snake_case)]
+                impl $trait_name for $typ {
+                    $(
+                        fn $method(self) -> Temperature<$unit> {
+                            Temperature::new(self as number)
+                        }
+                    )+
+                    $(
+                        fn $hidden(self) -> Temperature<$unit> {
+                            Temperature::new(self as number)
+                        }
+                    )+
+                }
+            };
+        }
+        temp_literal_impl!(f64);
+        temp_literal_impl!(f32);
+        temp_literal_impl!(i32);
+        temp_literal_impl!(i64);
+        temp_literal_impl!(u32);
+        temp_literal_impl!(u64);
+    };
+}
+
+temp_literal!(
+    Celsius,
+    CelsiusLiteral,
+    [degC, degrees_celsius],
+    hidden: [__unit__b0_C, __unit_degC]
+);
+temp_literal!(
+    Fahrenheit,
+    FahrenheitLiteral,
+    [degF, degrees_fahrenheit],
+    hidden: [__unit__b0_F, __unit_degF]
+);
@@ -1,132 +1,83 @@
-use std::fmt::Display;
-use std::fmt::LowerExp;
 use std::marker::PhantomData;
 
-use phys_calc_macros::impl_derived_conversions;
-use phys_calc_macros::Unit;
-
 use super::unit_traits::*;
+use crate::compound::impl_compound;
+use crate::dimension::dimension;
 use crate::math::*;
 use crate::math_helpers::*;
-use crate::math_impl::impl_math;
 use crate::unit::None;
-use crate::unspecialize;
 use crate::Unit;
 
-pub trait TimeUnit: Unit {
-    fn to_base(s: Time<Self>) -> Time<Second> {
-        Time {
-            inner: s.inner * Self::mult(),
-            types: PhantomData,
-        }
-    }
-    fn from_base(s: Time<Second>) -> Time<Self> {
-        Time {
-            inner: s.inner / Self::mult(),
-            types: PhantomData,
-        }
-    }
-    fn mult() -> number;
-    fn unit() -> &'static str;
-}
-
-impl_math!(Time<T: TimeUnit>);
-
-#[derive(Copy, Clone, Debug)]
-pub struct Time<T: TimeUnit> {
-    pub inner: number,
-    pub types: PhantomData<T>,
+dimension! {
+    Time<T: TimeUnit>,
+    slot: Time,
+    base: Second("s", latex = r"\second", name = "second"),
+    reference: Second,
 }
 
-impl<T: TimeUnit> Time<T> {
-    pub fn convert<D: TimeUnit>(self) -> Time<D> {
-        D::from_base(T::to_base(self))
-    }
-}
-
-impl<T> Display for Time<T>
-where
-    T: TimeUnit,
-{
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}{}", self.inner, T::unit())
-    }
-}
-
-impl<T> LowerExp for Time<T>
-where
-    T: TimeUnit,
-{
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{:e}{}", self.inner, T::unit())
-    }
-}
+impl_compound!(Time<T: TimeUnit>, Second);
 
 #[derive(Copy, Clone, Debug, Unit)]
 #[multiplier(1e-12)]
-#[unit("ps")]
+#[unit("ps", latex = r"\pico\second", name = "picosecond")]
 #[unit_impl(TimeUnit)]
 pub struct Picosecond;
 
 #[derive(Copy, Clone, Debug, Unit)]
 #[multiplier(1e-9)]
-#[unit("ns")]
+#[unit("ns", latex = r"\nano\second", name = "nanosecond")]
 #[unit_impl(TimeUnit)]
 pub struct Nanosecond;
 
 #[derive(Copy, Clone, Debug, Unit)]
 #[multiplier(1e-6)]
-#[unit("μs")]
+#[unit("μs", ascii = "us", latex = r"\micro\second", name = "microsecond")]
 #[unit_impl(TimeUnit)]
 pub struct Microsecond;
 
 #[derive(Copy, Clone, Debug, Unit)]
 #[multiplier(1e-3)]
-#[unit("ms")]
+#[unit("ms", latex = r"\milli\second", name = "millisecond")]
 #[unit_impl(TimeUnit)]
 pub struct Millisecond;
 
-#[derive(Copy, Clone, Debug, Unit)]
-#[multiplier(1.)]
-#[unit("s")]
-#[unit_impl(TimeUnit)]
-pub struct Second;
-
 #[derive(Copy, Clone, Debug, Unit)]
 #[multiplier(60.)]
-#[unit("min")]
+#[unit("min", latex = r"\minute", name = "minute")]
 #[unit_impl(TimeUnit)]
 pub struct Minute;
 
 #[derive(Copy, Clone, Debug, Unit)]
 #[multiplier(3600.)]
-#[unit("hr")]
+#[unit("hr", latex = r"\hour", name = "hour")]
 #[unit_impl(TimeUnit)]
 pub struct Hour;
 
 #[derive(Copy, Clone, Debug, Unit)]
 #[multiplier(86400.)]
-#[unit("day")]
+#[unit("day", latex = r"\day", name = "day")]
 #[unit_impl(TimeUnit)]
 pub struct Day;
 
 #[derive(Copy, Clone, Debug, Unit)]
 #[multiplier(2629800.)] // 365.25 / 12 * 86400
-#[unit("month")]
+#[unit("month", latex = r"\text{month}", name = "month")]
 #[unit_impl(TimeUnit)]
 pub struct Month;
 
 #[derive(Copy, Clone, Debug, Unit)]
 #[multiplier(31557600.)]
-#[unit("yr")]
+#[unit("yr", latex = r"\text{yr}", name = "year")]
 #[unit_impl(TimeUnit)]
 pub struct Year;
 
 #[derive(Copy, Clone, Debug, Unit)]
 #[multiplier(4.348e17)]
-#[unit("AgeOfUniverse")]
+#[unit(
+    "AgeOfUniverse",
+    latex = r"\text{AgeOfUniverse}",
+    name = "age of the universe",
+    plural = "ages of the universe"
+)]
 #[unit_impl(TimeUnit)]
 pub struct AgeOfUniverse;
-
-unspecialize!(Time<T: TimeUnit>);
-impl_derived_conversions!(Time<T: TimeUnit>, Time: One, T);
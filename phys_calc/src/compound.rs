@@ -0,0 +1,273 @@
+use std::fmt::Display;
+
+use crate::format::SymbolStyle;
+use crate::math::number;
+use crate::unit::Symbols;
+
+// A unit that can take part in a compound representation of a quantity whose
+// base unit is `B`, e.g. `Foot` and `Inch` for `Meter`.
+pub trait ChainLink<B> {
+    fn link_mult() -> number;
+    fn link_symbols() -> Symbols;
+}
+
+// A descending chain of units, written as a tuple: `(Foot, Inch)`,
+// `(Hour, Minute, Second)`, `(Degree, Arcminute, Arcsecond)`.
+pub trait UnitChain<B> {
+    fn links() -> Vec<(number, Symbols)>;
+}
+
+macro_rules! impl_unit_chain {
+    ($($link:ident),+) => {
+        impl<B, $($link: ChainLink<B>),+> UnitChain<B> for ($($link,)+) {
+            fn links() -> Vec<(number, Symbols)> {
+                vec![$(($link::link_mult(), $link::link_symbols())),+]
+            }
+        }
+    };
+}
+
+impl_unit_chain!(U1);
+impl_unit_chain!(U1, U2);
+impl_unit_chain!(U1, U2, U3);
+impl_unit_chain!(U1, U2, U3, U4);
+impl_unit_chain!(U1, U2, U3, U4, U5);
+
+#[derive(Clone, Debug)]
+pub struct Compound {
+    base: number,
+    links: Vec<(number, Symbols)>,
+    decimals: usize,
+    style: SymbolStyle,
+}
+
+impl Compound {
+    pub fn new(base: number, links: Vec<(number, Symbols)>) -> Self {
+        Compound {
+            base,
+            links,
+            decimals: 0,
+            style: SymbolStyle::Unicode,
+        }
+    }
+
+    // Decimal places kept on the last component; the formatter's precision
+    // overrides it.
+    pub fn decimals(self, decimals: usize) -> Self {
+        Compound { decimals, ..self }
+    }
+
+    pub fn style(self, style: SymbolStyle) -> Self {
+        Compound { style, ..self }
+    }
+
+    // Splits the value into one count per unit. Only the last component is
+    // rounded, so carries propagate upwards (59.6″ becomes 1′0″).
+    pub fn parts(&self, decimals: usize) -> Vec<(number, Symbols)> {
+        let Some(&(last_mult, _)) = self.links.last() else {
+            return vec![];
+        };
+        let scale = 10f64.powi(decimals as i32);
+        let mut remaining = (self.base.abs() / last_mult * scale).round() / scale;
+
+        let mut parts = vec![];
+        for &(mult, unit) in &self.links[..self.links.len() - 1] {
+            let ratio = snap(mult / last_mult);
+            let count = (remaining / ratio).floor();
+            remaining -= count * ratio;
+            parts.push((count, unit));
+        }
+        parts.push((remaining, self.links[self.links.len() - 1].1));
+        parts
+    }
+}
+
+// Ratios between related units are usually whole numbers (12 in/ft, 60 s/min),
+// but rarely come out exact in floating point.
+fn snap(ratio: number) -> number {
+    if (ratio - ratio.round()).abs() < 1e-9 * ratio {
+        ratio.round()
+    } else {
+        ratio
+    }
+}
+
+// Symbols like `°`, `′` and `″` are written directly after the number and
+// without a separator before the next component: `12°34′56″`. So are the
+// superscript letters of hour angles, `5ʰ35ᵐ17ˢ`.
+fn attached(unit: &str) -> bool {
+    !unit.starts_with(char::is_alphanumeric) || unit.starts_with(['ʰ', 'ᵐ', 'ˢ'])
+}
+
+fn component(count: &str, symbols: &Symbols, style: SymbolStyle) -> (String, &'static str) {
+    let unit = match style {
+        SymbolStyle::Unicode => symbols.unicode,
+        SymbolStyle::Ascii => symbols.ascii,
+        SymbolStyle::Latex => return (format!("\\SI{{{count}}}{{{}}}", symbols.latex), " "),
+        SymbolStyle::Name if count == "1" => symbols.singular,
+        SymbolStyle::Name => symbols.plural,
+    };
+    // The ASCII forms of attached symbols still follow the number, but are
+    // words that need a space before the next component: `5h 35m 17s`.
+    match (style, attached(symbols.unicode), attached(unit)) {
+        (_, _, true) => (format!("{count}{unit}"), ""),
+        (SymbolStyle::Ascii, true, false) => (format!("{count}{unit}"), " "),
+        _ => (format!("{count} {unit}"), " "),
+    }
+}
+
+impl Display for Compound {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let decimals = f.precision().unwrap_or(self.decimals);
+        let parts = self.parts(decimals);
+        // Leading zero components are dropped, but at least the last one is kept.
+        let first = parts
+            .iter()
+            .position(|&(count, _)| count != 0.)
+            .unwrap_or(parts.len().saturating_sub(1));
+
+        if self.base < 0. && parts.iter().any(|&(count, _)| count != 0.) {
+            write!(f, "-")?;
+        }
+        let mut separator = "";
+        for (i, (count, symbols)) in parts.iter().enumerate().skip(first) {
+            let precision = if i == parts.len() - 1 { decimals } else { 0 };
+            let (text, next) = component(&format!("{count:.precision$}"), symbols, self.style);
+            write!(f, "{separator}{text}")?;
+            separator = next;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CompoundParseError {
+    Empty,
+    InvalidNumber(String),
+    MissingUnit(String),
+    UnknownUnit(String),
+}
+
+impl Display for CompoundParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CompoundParseError::Empty => write!(f, "no quantity to parse"),
+            CompoundParseError::InvalidNumber(s) => write!(f, "invalid number at `{s}`"),
+            CompoundParseError::MissingUnit(s) => write!(f, "missing unit after `{s}`"),
+            CompoundParseError::UnknownUnit(s) => write!(f, "unknown unit at `{s}`"),
+        }
+    }
+}
+
+impl std::error::Error for CompoundParseError {}
+
+// Other spellings accepted when parsing, next to the unit's own symbols.
+const ALIASES: [(&str, &str); 1] = [("hr", "h")];
+
+// Parses strings like `5 ft 3 in`, `-1 h 30 min` or `12°34′56.5″` into a value
+// in the base unit, accepting the Unicode or ASCII symbols of the given chain.
+pub fn parse_compound(s: &str, links: &[(number, Symbols)]) -> Result<number, CompoundParseError> {
+    let mut rest = s.trim_start();
+    let negative = rest.starts_with('-');
+    if negative {
+        rest = rest[1..].trim_start();
+    }
+    if rest.is_empty() {
+        return Err(CompoundParseError::Empty);
+    }
+
+    let mut total = 0.;
+    while !rest.is_empty() {
+        let len = number_len(rest);
+        let count = rest[..len]
+            .parse::<number>()
+            .map_err(|_| CompoundParseError::InvalidNumber(rest.to_string()))?;
+        let after_number = rest[len..].trim_start();
+
+        // Longest match, so that e.g. `min` wins over `mi`.
+        let (mult, unit) = links
+            .iter()
+            .flat_map(|(mult, symbols)| {
+                let aliases = ALIASES
+                    .iter()
+                    .filter(|(unit, _)| [symbols.unicode, symbols.ascii].contains(unit))
+                    .map(move |&(_, alias)| (mult, alias));
+                [(mult, symbols.unicode), (mult, symbols.ascii)]
+                    .into_iter()
+                    .chain(aliases)
+            })
+            .filter(|(_, unit)| !unit.is_empty() && after_number.starts_with(unit))
+            .max_by_key(|(_, unit)| unit.len())
+            .ok_or_else(|| {
+                if after_number.is_empty() {
+                    CompoundParseError::MissingUnit(rest.to_string())
+                } else {
+                    CompoundParseError::UnknownUnit(after_number.to_string())
+                }
+            })?;
+        total += count * mult;
+        rest = after_number[unit.len()..].trim_start();
+    }
+
+    Ok(if negative { -total } else { total })
+}
+
+fn number_len(s: &str) -> usize {
+    let bytes = s.as_bytes();
+    let digits = |mut i: usize| {
+        while i < bytes.len() && bytes[i].is_ascii_digit() {
+            i += 1;
+        }
+        i
+    };
+    let mut i = digits(0);
+    if i < bytes.len() && bytes[i] == b'.' {
+        i = digits(i + 1);
+    }
+    // Only treat `e` as an exponent if digits follow, so units starting with
+    // `e` are not swallowed.
+    if i < bytes.len() && (bytes[i] == b'e' || bytes[i] == b'E') {
+        let mut j = i + 1;
+        if j < bytes.len() && (bytes[j] == b'+' || bytes[j] == b'-') {
+            j += 1;
+        }
+        if j < bytes.len() && bytes[j].is_ascii_digit() {
+            i = digits(j);
+        }
+    }
+    i
+}
+
+macro_rules! impl_compound {
+    ($struct_name:ident<$generic:ident : $trait:ident>, $base:ident) => {
+        impl<U: $trait> $crate::compound::ChainLink<$base> for U {
+            fn link_mult() -> number {
+                U::mult()
+            }
+            fn link_symbols() -> $crate::unit::Symbols {
+                U::symbols()
+            }
+        }
+
+        impl<$generic: $trait> $struct_name<$generic> {
+            pub fn compound<Chain: $crate::compound::UnitChain<$base>>(
+                &self,
+            ) -> $crate::compound::Compound {
+                $crate::compound::Compound::new(self.convert::<$base>().inner, Chain::links())
+            }
+
+            pub fn parse_compound<Chain: $crate::compound::UnitChain<$base>>(
+                s: &str,
+            ) -> Result<Self, $crate::compound::CompoundParseError> {
+                let base = $crate::compound::parse_compound(s, &Chain::links())?;
+                Ok($struct_name::<$base> {
+                    inner: base,
+                    types: PhantomData,
+                }
+                .convert())
+            }
+        }
+    };
+}
+
+pub(crate) use impl_compound;
@@ -0,0 +1,35 @@
+use std::marker::PhantomData;
+
+use super::Kinematic;
+use crate::base::length::Meter;
+use crate::base::time::Second;
+use crate::dimension::dimension;
+use crate::math::*;
+use crate::math_helpers::*;
+use crate::Unit;
+
+dimension! {
+    Acceleration<AC: AccelerationUnit>,
+    derived: Kinematic<One, Meter, NegTwo, Second>,
+    base: MeterPerSecondSquared(
+        "m/s²",
+        ascii = "m/s2",
+        latex = r"\metre\per\second\squared",
+        name = "meter per second squared",
+        plural = "meters per second squared",
+    ),
+    reference: MeterPerSecondSquared,
+}
+
+#[derive(Copy, Clone, Debug, Unit)]
+#[multiplier(0.3048)]
+#[unit(
+    "ft/s²",
+    ascii = "ft/s2",
+    latex = r"\text{ft}\per\second\squared",
+    name = "foot per second squared",
+    plural = "feet per second squared"
+)]
+#[unit_impl(AccelerationUnit)]
+#[unit_quantity(Acceleration)]
+pub struct FootPerSecondSquared;
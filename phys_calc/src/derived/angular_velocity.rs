@@ -0,0 +1,85 @@
+use std::marker::PhantomData;
+
+use super::frequency::{Frequency, FrequencyUnit, Hertz};
+use crate::base::angle::{Angle, AngleUnit, Pi, Radian};
+use crate::base::time::{Second, Time, TimeUnit};
+use crate::dimension::dimension;
+use crate::math::*;
+use crate::math_helpers::*;
+use crate::unit::None;
+use crate::Unit;
+
+dimension! {
+    AngularVelocity<AV: AngularVelocityUnit>,
+    derived: Derived<
+        LengthPower<Zero, None>,
+        TimePower<NegOne, Second>,
+        TempPower<Zero, None>,
+        MassPower<Zero, None>,
+        CurrentPower<Zero, None>,
+        LuminousIntensityPower<Zero, None>,
+        QuantityPower<Zero, None>,
+        AnglePower<One, Radian>,
+        SolidAnglePower<Zero, None>,
+        DigitalInformationPower<Zero, None>,
+    >,
+    base: RadianPerSecond(
+        "rad/s",
+        latex = r"\radian\per\second",
+        name = "radian per second",
+        plural = "radians per second",
+    ),
+    reference: RadianPerSecond,
+}
+
+// Every cycle sweeps a full turn.
+fn cycle() -> Angle<Radian> {
+    Angle::new(2. * number::pi())
+}
+
+impl<AV: AngularVelocityUnit> AngularVelocity<AV> {
+    pub fn to_frequency(self) -> Frequency<Hertz> {
+        let omega = self.convert::<RadianPerSecond>().to_derived();
+        DimensionOp::<_, Per>::apply(omega, cycle().to_derived()).convert()
+    }
+}
+
+impl<F: FrequencyUnit> Frequency<F> {
+    pub fn to_angular_velocity(self) -> AngularVelocity<RadianPerSecond> {
+        DimensionOp::<_, Times>::apply(cycle().to_derived(), self.to_derived()).convert()
+    }
+}
+
+// `90.deg() / 2.s()`, the angle swept per time.
+impl<A: AngleUnit, T: TimeUnit> std::ops::Div<Time<T>> for Angle<A> {
+    type Output = AngularVelocity<RadianPerSecond>;
+
+    fn div(self, time: Time<T>) -> Self::Output {
+        AngularVelocity::new(self.value_in::<Radian>() / time.value_in::<Second>())
+    }
+}
+
+#[derive(Copy, Clone, Debug, Unit)]
+#[multiplier(number::pi() / 180.)]
+#[unit(
+    "°/s",
+    ascii = "deg/s",
+    latex = r"\degree\per\second",
+    name = "degree per second",
+    plural = "degrees per second"
+)]
+#[unit_impl(AngularVelocityUnit)]
+#[unit_quantity(AngularVelocity)]
+pub struct DegreePerSecond;
+
+#[derive(Copy, Clone, Debug, Unit)]
+#[multiplier(number::pi() / 30.)]
+#[unit(
+    "rpm",
+    latex = r"\text{rpm}",
+    name = "revolution per minute",
+    plural = "revolutions per minute"
+)]
+#[unit_impl(AngularVelocityUnit)]
+#[unit_quantity(AngularVelocity)]
+pub struct RevolutionPerMinute;
@@ -0,0 +1,67 @@
+use std::marker::PhantomData;
+
+use super::Kinematic;
+use crate::base::length::Meter;
+use crate::dimension::dimension;
+use crate::math::*;
+use crate::math_helpers::*;
+use crate::unit::None;
+use crate::Unit;
+
+dimension! {
+    Area<AR: AreaUnit>,
+    derived: Kinematic<Two, Meter, Zero, None>,
+    base: SquareMeter("m²", ascii = "m2", latex = r"\square\metre", name = "square meter"),
+    reference: derived,
+}
+
+#[derive(Copy, Clone, Debug, Unit)]
+#[multiplier(1e-4)]
+#[unit(
+    "cm²",
+    ascii = "cm2",
+    latex = r"\square\centi\metre",
+    name = "square centimeter"
+)]
+#[unit_impl(AreaUnit)]
+#[unit_quantity(Area)]
+pub struct SquareCentimeter;
+
+#[derive(Copy, Clone, Debug, Unit)]
+#[multiplier(1e4)]
+#[unit("ha", latex = r"\hectare", name = "hectare")]
+#[unit_impl(AreaUnit)]
+#[unit_quantity(Area)]
+pub struct Hectare;
+
+#[derive(Copy, Clone, Debug, Unit)]
+#[multiplier(1e6)]
+#[unit(
+    "km²",
+    ascii = "km2",
+    latex = r"\square\kilo\metre",
+    name = "square kilometer"
+)]
+#[unit_impl(AreaUnit)]
+#[unit_quantity(Area)]
+pub struct SquareKilometer;
+
+#[derive(Copy, Clone, Debug, Unit)]
+#[multiplier(0.09290304)]
+#[unit(
+    "ft²",
+    ascii = "ft2",
+    latex = r"\text{ft}\squared",
+    name = "square foot",
+    plural = "square feet"
+)]
+#[unit_impl(AreaUnit)]
+#[unit_quantity(Area)]
+pub struct SquareFoot;
+
+#[derive(Copy, Clone, Debug, Unit)]
+#[multiplier(4046.8564224)]
+#[unit("ac", latex = r"\text{ac}", name = "acre")]
+#[unit_impl(AreaUnit)]
+#[unit_quantity(Area)]
+pub struct Acre;
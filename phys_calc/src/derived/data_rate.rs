@@ -0,0 +1,146 @@
+use std::marker::PhantomData;
+
+use crate::base::digital_information::{Bit, DigitalInformation, DigitalInformationUnit};
+use crate::base::time::{Second, Time, TimeUnit};
+use crate::dimension::dimension;
+use crate::format::Engineering;
+use crate::math::*;
+use crate::math_helpers::*;
+use crate::unit::None;
+use crate::Unit;
+
+dimension! {
+    DataRate<R: DataRateUnit>,
+    derived: Derived<
+        LengthPower<Zero, None>,
+        TimePower<NegOne, Second>,
+        TempPower<Zero, None>,
+        MassPower<Zero, None>,
+        CurrentPower<Zero, None>,
+        LuminousIntensityPower<Zero, None>,
+        QuantityPower<Zero, None>,
+        AnglePower<Zero, None>,
+        SolidAnglePower<Zero, None>,
+        DigitalInformationPower<One, Bit>,
+    >,
+    base: BitPerSecond(
+        "bit/s",
+        latex = r"\bit\per\second",
+        name = "bit per second",
+        plural = "bits per second",
+    ),
+    reference: BitPerSecond,
+}
+
+impl<R: DataRateUnit> DataRate<R> {
+    // The rate of moving `amount` in `time`.
+    pub fn from_transfer<D: DigitalInformationUnit, T: TimeUnit>(
+        amount: DigitalInformation<D>,
+        time: Time<T>,
+    ) -> Self {
+        DataRate::<BitPerSecond>::new(amount.value_in::<Bit>() / time.value_in::<Second>())
+            .convert()
+    }
+
+    pub fn transferred_in<T: TimeUnit>(self, time: Time<T>) -> DigitalInformation<Bit> {
+        DigitalInformation::new(self.value_in::<BitPerSecond>() * time.value_in::<Second>())
+    }
+
+    // Network rates are usually given in bits with decimal prefixes, which is
+    // what `engineering` does, storage throughput in bytes with binary ones.
+    pub fn engineering_binary(&self) -> Engineering {
+        Engineering::new(
+            self.value_in::<BytePerSecond>(),
+            <BytePerSecond as DataRateUnit>::symbols(),
+        )
+        .binary()
+    }
+}
+
+impl<D: DigitalInformationUnit> DigitalInformation<D> {
+    // How long moving this takes at `rate`, e.g. 40 GiB at 1 Gbps.
+    pub fn transfer_time<R: DataRateUnit>(self, rate: DataRate<R>) -> Time<Second> {
+        Time::new(self.value_in::<Bit>() / rate.value_in::<BitPerSecond>())
+    }
+}
+
+// `100.MB() / 8.s()`, the same as `DataRate::from_transfer`.
+impl<D: DigitalInformationUnit, T: TimeUnit> std::ops::Div<Time<T>> for DigitalInformation<D> {
+    type Output = DataRate<BitPerSecond>;
+
+    fn div(self, time: Time<T>) -> Self::Output {
+        DataRate::from_transfer(self, time)
+    }
+}
+
+#[derive(Copy, Clone, Debug, Unit)]
+#[multiplier(8)]
+#[unit(
+    "B/s",
+    latex = r"\byte\per\second",
+    name = "byte per second",
+    plural = "bytes per second"
+)]
+#[unit_impl(DataRateUnit)]
+#[unit_quantity(DataRate)]
+pub struct BytePerSecond;
+
+#[derive(Copy, Clone, Debug, Unit)]
+#[multiplier(1e3)]
+#[unit(
+    "kbps",
+    latex = r"\kilo\bit\per\second",
+    name = "kilobit per second",
+    plural = "kilobits per second"
+)]
+#[unit_impl(DataRateUnit)]
+#[unit_quantity(DataRate)]
+pub struct KilobitPerSecond;
+
+#[derive(Copy, Clone, Debug, Unit)]
+#[multiplier(1e6)]
+#[unit(
+    "Mbps",
+    latex = r"\mega\bit\per\second",
+    name = "megabit per second",
+    plural = "megabits per second"
+)]
+#[unit_impl(DataRateUnit)]
+#[unit_quantity(DataRate)]
+pub struct MegabitPerSecond;
+
+#[derive(Copy, Clone, Debug, Unit)]
+#[multiplier(1e9)]
+#[unit(
+    "Gbps",
+    latex = r"\giga\bit\per\second",
+    name = "gigabit per second",
+    plural = "gigabits per second"
+)]
+#[unit_impl(DataRateUnit)]
+#[unit_quantity(DataRate)]
+pub struct GigabitPerSecond;
+
+#[derive(Copy, Clone, Debug, Unit)]
+#[multiplier(8e6)]
+#[unit(
+    "MB/s",
+    latex = r"\mega\byte\per\second",
+    name = "megabyte per second",
+    plural = "megabytes per second"
+)]
+#[unit_impl(DataRateUnit)]
+#[unit_quantity(DataRate)]
+pub struct MegabytePerSecond;
+
+#[derive(Copy, Clone, Debug, Unit)]
+#[multiplier(8*1024*1024)]
+#[unit(
+    "MiB/s",
+    latex = r"\mebi\byte\per\second",
+    name = "mebibyte per second",
+    plural = "mebibytes per second"
+)]
+#[unit_impl(DataRateUnit)]
+#[unit_quantity(DataRate)]
+pub struct MebibytePerSecond;
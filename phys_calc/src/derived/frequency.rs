@@ -0,0 +1,66 @@
+use std::marker::PhantomData;
+
+use super::Kinematic;
+use crate::base::time::{Second, Time, TimeUnit};
+use crate::dimension::dimension;
+use crate::math::*;
+use crate::math_helpers::*;
+use crate::unit::None;
+use crate::Unit;
+
+dimension! {
+    Frequency<F: FrequencyUnit>,
+    derived: Kinematic<Zero, None, NegOne, Second>,
+    base: Hertz("Hz", latex = r"\hertz", name = "hertz", plural = "hertz"),
+    reference: Hertz,
+}
+
+impl<F: FrequencyUnit> Frequency<F> {
+    // The duration of one cycle.
+    pub fn period(self) -> Time<Second> {
+        Time::new(1. / self.value_in::<Hertz>())
+    }
+}
+
+impl<T: TimeUnit> Time<T> {
+    // How often something with this period repeats.
+    pub fn to_frequency(self) -> Frequency<Hertz> {
+        Frequency::new(1. / self.value_in::<Second>())
+    }
+}
+
+#[derive(Copy, Clone, Debug, Unit)]
+#[multiplier(1e3)]
+#[unit(
+    "kHz",
+    latex = r"\kilo\hertz",
+    name = "kilohertz",
+    plural = "kilohertz"
+)]
+#[unit_impl(FrequencyUnit)]
+#[unit_quantity(Frequency)]
+pub struct Kilohertz;
+
+#[derive(Copy, Clone, Debug, Unit)]
+#[multiplier(1e6)]
+#[unit(
+    "MHz",
+    latex = r"\mega\hertz",
+    name = "megahertz",
+    plural = "megahertz"
+)]
+#[unit_impl(FrequencyUnit)]
+#[unit_quantity(Frequency)]
+pub struct Megahertz;
+
+#[derive(Copy, Clone, Debug, Unit)]
+#[multiplier(1e9)]
+#[unit(
+    "GHz",
+    latex = r"\giga\hertz",
+    name = "gigahertz",
+    plural = "gigahertz"
+)]
+#[unit_impl(FrequencyUnit)]
+#[unit_quantity(Frequency)]
+pub struct Gigahertz;
@@ -0,0 +1,40 @@
+use crate::math::Zero;
+use crate::math_helpers::*;
+use crate::unit::None;
+
+// Dimensions made of several base dimensions that have units of their own,
+// liters rather than dm³, knots rather than nmi/hr.
+pub mod acceleration;
+pub mod angular_velocity;
+pub mod area;
+pub mod data_rate;
+pub mod frequency;
+pub mod ratio;
+pub mod velocity;
+pub mod volume;
+
+pub mod unit_traits {
+    pub use super::acceleration::AccelerationUnit;
+    pub use super::angular_velocity::AngularVelocityUnit;
+    pub use super::area::AreaUnit;
+    pub use super::data_rate::DataRateUnit;
+    pub use super::frequency::FrequencyUnit;
+    pub use super::ratio::RatioUnit;
+    pub use super::velocity::VelocityUnit;
+    pub use super::volume::VolumeUnit;
+}
+
+// A `Derived` in length and time only, what areas, volumes, velocities,
+// accelerations, frequencies and ratios convert through.
+pub type Kinematic<LE, L, TE, T> = Derived<
+    LengthPower<LE, L>,
+    TimePower<TE, T>,
+    TempPower<Zero, None>,
+    MassPower<Zero, None>,
+    CurrentPower<Zero, None>,
+    LuminousIntensityPower<Zero, None>,
+    QuantityPower<Zero, None>,
+    AnglePower<Zero, None>,
+    SolidAnglePower<Zero, None>,
+    DigitalInformationPower<Zero, None>,
+>;
@@ -0,0 +1,87 @@
+use std::marker::PhantomData;
+
+use super::Kinematic;
+use crate::dimension::dimension;
+use crate::math::*;
+use crate::math_helpers::*;
+use crate::unit::None;
+use crate::Unit;
+
+// What dividing a quantity by another of its kind gives, so that efficiencies
+// and concentrations keep a type. `number::from` gets the plain fraction back.
+// Both sides have to be in the same unit, `km / m` needs a `convert` first.
+dimension! {
+    Ratio<R: RatioUnit>,
+    derived: Kinematic<Zero, None, Zero, None>,
+    base: Fraction("", latex = "", name = "fraction"),
+    reference: Fraction,
+}
+
+impl<R: RatioUnit> From<Ratio<R>> for number {
+    fn from(ratio: Ratio<R>) -> number {
+        ratio.value_in::<Fraction>()
+    }
+}
+
+#[derive(Copy, Clone, Debug, Unit)]
+#[multiplier(1e-2)]
+#[unit(
+    "%",
+    ascii = "pct",
+    latex = r"\percent",
+    name = "percent",
+    plural = "percent"
+)]
+#[unit_impl(RatioUnit)]
+#[unit_quantity(Ratio)]
+pub struct Percent;
+
+#[derive(Copy, Clone, Debug, Unit)]
+#[multiplier(1e-3)]
+#[unit(
+    "‰",
+    ascii = "permille",
+    latex = r"\text{\textperthousand}",
+    name = "permille",
+    plural = "permille"
+)]
+#[unit_impl(RatioUnit)]
+#[unit_quantity(Ratio)]
+pub struct Permille;
+
+#[derive(Copy, Clone, Debug, Unit)]
+#[multiplier(1e-6)]
+#[unit(
+    "ppm",
+    latex = r"\text{ppm}",
+    name = "part per million",
+    plural = "parts per million"
+)]
+#[unit_impl(RatioUnit)]
+#[unit_quantity(Ratio)]
+pub struct PartPerMillion;
+
+#[derive(Copy, Clone, Debug, Unit)]
+#[multiplier(1e-9)]
+#[unit(
+    "ppb",
+    latex = r"\text{ppb}",
+    name = "part per billion",
+    plural = "parts per billion"
+)]
+#[unit_impl(RatioUnit)]
+#[unit_quantity(Ratio)]
+pub struct PartPerBillion;
+
+// Parts per trillion (1e-12). Parts per thousand are `Permille`.
+#[derive(Copy, Clone, Debug, Unit)]
+#[multiplier(1e-12)]
+#[unit(
+    "ppt",
+    latex = r"\text{ppt}",
+    name = "part per trillion",
+    plural = "parts per trillion"
+)]
+#[unit_impl(RatioUnit)]
+#[unit_quantity(Ratio)]
+pub struct PartPerTrillion;
@@ -0,0 +1,65 @@
+use std::marker::PhantomData;
+
+use super::Kinematic;
+use crate::base::length::Meter;
+use crate::base::time::Second;
+use crate::dimension::dimension;
+use crate::math::*;
+use crate::math_helpers::*;
+use crate::Unit;
+
+dimension! {
+    Velocity<V: VelocityUnit>,
+    derived: Kinematic<One, Meter, NegOne, Second>,
+    base: MeterPerSecond(
+        "m/s",
+        latex = r"\metre\per\second",
+        name = "meter per second",
+        plural = "meters per second",
+    ),
+    reference: MeterPerSecond,
+}
+
+#[derive(Copy, Clone, Debug, Unit)]
+#[multiplier(1. / 3.6)]
+#[unit(
+    "km/h",
+    latex = r"\kilo\metre\per\hour",
+    name = "kilometer per hour",
+    plural = "kilometers per hour"
+)]
+#[unit_impl(VelocityUnit)]
+#[unit_quantity(Velocity)]
+pub struct KilometerPerHour;
+
+#[derive(Copy, Clone, Debug, Unit)]
+#[multiplier(0.3048)]
+#[unit(
+    "ft/s",
+    latex = r"\text{ft}\per\second",
+    name = "foot per second",
+    plural = "feet per second"
+)]
+#[unit_impl(VelocityUnit)]
+#[unit_quantity(Velocity)]
+pub struct FootPerSecond;
+
+#[derive(Copy, Clone, Debug, Unit)]
+#[multiplier(0.44704)]
+#[unit(
+    "mph",
+    latex = r"\text{mph}",
+    name = "mile per hour",
+    plural = "miles per hour"
+)]
+#[unit_impl(VelocityUnit)]
+#[unit_quantity(Velocity)]
+pub struct MilePerHour;
+
+// One nautical mile (1852 m) per hour.
+#[derive(Copy, Clone, Debug, Unit)]
+#[multiplier(1852. / 3600.)]
+#[unit("kn", latex = r"\text{kn}", name = "knot")]
+#[unit_impl(VelocityUnit)]
+#[unit_quantity(Velocity)]
+pub struct Knot;
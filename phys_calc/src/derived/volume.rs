@@ -0,0 +1,39 @@
+use std::marker::PhantomData;
+
+use super::Kinematic;
+use crate::base::length::Meter;
+use crate::dimension::dimension;
+use crate::math::*;
+use crate::math_helpers::*;
+use crate::unit::None;
+use crate::Unit;
+
+// Liters take the SI prefixes (mL, kL), cubic meters don't.
+dimension! {
+    Volume<VO: VolumeUnit>,
+    derived: Kinematic<Three, Meter, Zero, None>,
+    base: CubicMeter("m³", ascii = "m3", latex = r"\cubic\metre", name = "cubic meter"),
+    reference: Liter,
+}
+
+#[derive(Copy, Clone, Debug, Unit)]
+#[multiplier(1e-6)]
+#[unit("mL", latex = r"\milli\litre", name = "milliliter")]
+#[unit_impl(VolumeUnit)]
+#[unit_quantity(Volume)]
+pub struct Milliliter;
+
+#[derive(Copy, Clone, Debug, Unit)]
+#[multiplier(1e-3)]
+#[unit("L", latex = r"\litre", name = "liter")]
+#[unit_impl(VolumeUnit)]
+#[unit_quantity(Volume)]
+pub struct Liter;
+
+// The US liquid gallon.
+#[derive(Copy, Clone, Debug, Unit)]
+#[multiplier(3.785411784e-3)]
+#[unit("gal", latex = r"\text{gal}", name = "gallon")]
+#[unit_impl(VolumeUnit)]
+#[unit_quantity(Volume)]
+pub struct Gallon;
@@ -0,0 +1,148 @@
+// Generates a whole base dimension: the unit trait, the quantity type with its
+// constructors, formatting and math, the base unit and the conversions to and
+// from `Derived`.
+//
+// dimension! {
+//     Length<L: LengthUnit>,
+//     slot: Length,
+//     base: Meter("m", latex = r"\metre", name = "meter"),
+//     reference: Meter,
+// }
+//
+// `base` is declared here with a multiplier of 1, so it can't disagree with the
+// other units about what the base is. `reference` is the unit SI prefixes
+// attach to when formatting (gram rather than kilogram), and `slot` is the
+// dimension's slot in `Derived`, whose type parameter name (`L`, `TMP`, `LI`,
+// ...) the generic has to match. The file invoking it needs the usual imports
+// of `PhantomData`, `math::*`, `math_helpers::*` and `unit::None`.
+//
+// Named dimensions made of several base dimensions (volume, velocity, ...)
+// take `derived` instead of `slot`, the `Derived` in base units they convert
+// through. Their units' multipliers are relative to that:
+//
+// dimension! {
+//     Volume<VO: VolumeUnit>,
+//     derived: Kinematic<Three, Meter, Zero, None>,
+//     base: CubicMeter("m³", ascii = "m3", latex = r"\cubic\metre", name = "cubic meter"),
+//     reference: Liter,
+// }
+//
+// `reference: derived` formats them as that `Derived` instead, for units that
+// are a power of another unit (m², not km² for 1500 m²).
+macro_rules! dimension {
+    (
+        $name:ident<$generic:ident : $trait:ident>,
+        slot: $slot:ident,
+        base: $base:ident($($base_unit:tt)*),
+        reference: $reference:ident $(,)?
+    ) => {
+        $crate::dimension::dimension!(@quantity $name<$generic: $trait>, $base($($base_unit)*), $reference);
+
+        $crate::unspecialize!($name<$generic: $trait>);
+        phys_calc_macros::impl_derived_conversions!($name<$generic: $trait>, $slot: One, $generic);
+    };
+    (
+        $name:ident<$generic:ident : $trait:ident>,
+        derived: $derived:ty,
+        base: $base:ident($($base_unit:tt)*),
+        reference: $reference:ident $(,)?
+    ) => {
+        $crate::dimension::dimension!(@quantity $name<$generic: $trait>, $base($($base_unit)*), $reference);
+
+        impl<$generic: $trait> UnitToDerived for $name<$generic> {
+            type Dimension = $derived;
+            fn to_derived(self) -> Self::Dimension {
+                Derived::new(self.inner * $generic::mult())
+            }
+        }
+
+        impl<$generic: $trait, Rhs> ConvertFrom<Rhs> for $name<$generic>
+        where
+            $derived: ConvertFrom<Rhs>,
+        {
+            fn convert_from(other: Rhs) -> Self {
+                $name::new(<$derived>::convert_from(other).inner / $generic::mult())
+            }
+        }
+    };
+    (@quantity $name:ident<$generic:ident : $trait:ident>, $base:ident($($base_unit:tt)*), $reference:ident) => {
+        pub trait $trait: $crate::Unit {
+            fn to_base(s: $name<Self>) -> $name<$base> {
+                $name {
+                    inner: s.inner * Self::mult(),
+                    types: PhantomData,
+                }
+            }
+            fn from_base(s: $name<$base>) -> $name<Self> {
+                $name {
+                    inner: s.inner / Self::mult(),
+                    types: PhantomData,
+                }
+            }
+            fn unit() -> &'static str;
+            fn symbols() -> $crate::unit::Symbols;
+            fn mult() -> number;
+        }
+        $crate::math_impl::impl_math!($name<$generic: $trait>);
+        $crate::math_impl::impl_cmp!($name<$generic: $trait>);
+        $crate::math_impl::impl_rounding!($name<$generic: $trait>);
+        $crate::format::impl_format!($name<$generic: $trait>, $reference);
+
+        #[derive(Copy, Clone, Debug)]
+        pub struct $name<$generic: $trait> {
+            pub(crate) inner: number,
+            pub(crate) types: PhantomData<$generic>,
+        }
+
+        impl<$generic: $trait> $name<$generic> {
+            pub const ZERO: Self = Self::new(0.);
+
+            pub const fn new(value: number) -> Self {
+                $name {
+                    inner: value,
+                    types: PhantomData,
+                }
+            }
+
+            pub const fn value(&self) -> number {
+                self.inner
+            }
+
+            pub fn value_in<U: $trait>(self) -> number {
+                self.convert::<U>().inner
+            }
+
+            pub fn convert<U: $trait>(self) -> $name<U> {
+                U::from_base($generic::to_base(self))
+            }
+        }
+
+        impl<$generic: $trait> Default for $name<$generic> {
+            fn default() -> Self {
+                Self::ZERO
+            }
+        }
+
+        impl<$generic: $trait> std::fmt::Display for $name<$generic> {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, "{}{}", self.inner, $generic::unit())
+            }
+        }
+
+        impl<$generic: $trait> std::fmt::LowerExp for $name<$generic> {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, "{:e}{}", self.inner, $generic::unit())
+            }
+        }
+
+        #[derive(Copy, Clone, Debug, $crate::Unit)]
+        #[multiplier(1.)]
+        #[unit($($base_unit)*)]
+        #[unit_impl($trait)]
+        #[unit_quantity($name)]
+        pub struct $base;
+
+    };
+}
+
+pub(crate) use dimension;
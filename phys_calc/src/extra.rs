@@ -0,0 +1,196 @@
+use std::marker::PhantomData;
+use std::ops::Add;
+
+use crate::math::*;
+use crate::math_helpers::*;
+use crate::unit::{None, Symbols};
+use crate::Unit;
+
+// Dimensions outside the built-in ten (pixels, currency, events, ...) live in
+// the last type parameter of `Derived`, a list with one entry per registered
+// dimension:
+//
+// pub struct Pixels;
+// impl ExtraDimension for Pixels {
+//     type Position = Here;
+//     type Reference = Pixel;
+// }
+//
+// #[derive(Copy, Clone, Debug, Unit)]
+// #[multiplier(1.)]
+// #[unit("px", latex = r"\text{px}", name = "pixel")]
+// #[unit_impl(ExtraUnit<Pixels>)]
+// pub struct Pixel;
+//
+// let width: Extra<Pixel> = 1920.px();
+//
+// The position decides where in the list the dimension goes, `Here` for the
+// first one, `Next<Here>` for the second and so on. Dimensions used together
+// need different positions, two units sharing one don't combine.
+pub trait ExtraDimension {
+    type Position: Position;
+    // The unit SI prefixes attach to when formatting.
+    type Reference: ExtraUnit;
+}
+
+pub trait ExtraUnit: Unit + EqualsOrZero {
+    type Dimension: ExtraDimension;
+    fn mult() -> number;
+    fn unit() -> &'static str;
+    fn symbols() -> Symbols;
+}
+
+impl ExtraDimension for None {
+    type Position = Here;
+    type Reference = None;
+}
+
+impl ExtraUnit for None {
+    type Dimension = None;
+
+    fn mult() -> number {
+        panic!("`None` is not a regular unit!")
+    }
+
+    fn unit() -> &'static str {
+        panic!("`None` is not a regular unit!")
+    }
+
+    fn symbols() -> Symbols {
+        panic!("`None` is not a regular unit!")
+    }
+}
+
+// The list itself, `ExtraPowers<E, U, Rest>` holds the exponent and unit of one
+// position. Positions in front of a dimension are padded with `Zero, None`.
+pub trait ExtraTrait {
+    fn terms() -> Vec<Term>;
+}
+
+pub struct NoExtra;
+pub struct ExtraPowers<E, U, Rest>(PhantomData<(E, U, Rest)>);
+
+impl ExtraTrait for NoExtra {
+    fn terms() -> Vec<Term> {
+        vec![]
+    }
+}
+
+impl<E: Number, U: ExtraUnit, Rest: ExtraTrait> ExtraTrait for ExtraPowers<E, U, Rest> {
+    fn terms() -> Vec<Term> {
+        // Zero-exponent positions may hold `None`, whose methods panic.
+        let term = (E::VALUE != 0).then(|| Term {
+            exponent: E::VALUE,
+            mult: U::mult(),
+            symbols: U::symbols(),
+            reference_symbols: <<U::Dimension as ExtraDimension>::Reference as ExtraUnit>::symbols(
+            ),
+            reference_mult: <<U::Dimension as ExtraDimension>::Reference as ExtraUnit>::mult(),
+        });
+        term.into_iter().chain(Rest::terms()).collect()
+    }
+}
+
+pub trait Position {
+    type List<E: Number, U: ExtraUnit>: ExtraTrait;
+}
+
+pub struct Here;
+pub struct Next<P: Position>(PhantomData<P>);
+
+impl Position for Here {
+    type List<E: Number, U: ExtraUnit> = ExtraPowers<E, U, NoExtra>;
+}
+
+impl<P: Position> Position for Next<P> {
+    type List<E: Number, U: ExtraUnit> = ExtraPowers<Zero, None, P::List<E, U>>;
+}
+
+// `U` to the power `E`, at its dimension's position.
+pub type ExtraList<E, U> =
+    <<<U as ExtraUnit>::Dimension as ExtraDimension>::Position as Position>::List<E, U>;
+
+// A quantity in one registered dimension, the counterpart of `Length<U>`.
+pub type Extra<U> = Derived<
+    LengthPower<Zero, None>,
+    TimePower<Zero, None>,
+    TempPower<Zero, None>,
+    MassPower<Zero, None>,
+    CurrentPower<Zero, None>,
+    LuminousIntensityPower<Zero, None>,
+    QuantityPower<Zero, None>,
+    AnglePower<Zero, None>,
+    SolidAnglePower<Zero, None>,
+    DigitalInformationPower<Zero, None>,
+    ExtraList<One, U>,
+>;
+
+// Lists with the same exponent at every position, a missing tail counting as
+// zero, and units of the same dimension wherever the exponent isn't zero.
+pub trait SameExtra<Rhs: ExtraTrait>: ExtraTrait {}
+
+impl SameExtra<NoExtra> for NoExtra {}
+impl<
+        E: Number,
+        U1: ExtraUnit + SameExtraDimension<E, U2>,
+        U2: ExtraUnit,
+        R1: SameExtra<R2>,
+        R2: ExtraTrait,
+    > SameExtra<ExtraPowers<E, U2, R2>> for ExtraPowers<E, U1, R1>
+{
+}
+impl<U: ExtraUnit, R: ExtraTrait> SameExtra<ExtraPowers<Zero, U, R>> for NoExtra where
+    NoExtra: SameExtra<R>
+{
+}
+impl<U: ExtraUnit, R: SameExtra<NoExtra>> SameExtra<NoExtra> for ExtraPowers<Zero, U, R> {}
+
+// Two dimensions registered at the same position would otherwise compare and
+// convert into each other. At a zero exponent either unit may be `None`.
+pub trait SameExtraDimension<E, U> {}
+
+impl<U1, U2> SameExtraDimension<Zero, U2> for U1 {}
+
+macro_rules! impl_same_extra_dimension {
+    ($($exponent:ident),+) => {
+        $(
+            impl<U1: ExtraUnit, U2: ExtraUnit<Dimension = U1::Dimension>>
+                SameExtraDimension<$exponent, U2> for U1
+            {
+            }
+        )+
+    };
+}
+
+impl_same_extra_dimension!(NegFive, NegFour, NegThree, NegTwo, NegOne, One, Two, Three, Four, Five);
+
+// `PowerOp` position by position, a missing tail counting as zero.
+impl<Op> PowerOp<NoExtra, Op> for NoExtra {
+    type Output = NoExtra;
+}
+impl<E, U, R, Op> PowerOp<NoExtra, Op> for ExtraPowers<E, U, R> {
+    type Output = Self;
+}
+impl<E, U, R> PowerOp<ExtraPowers<E, U, R>, Times> for NoExtra {
+    type Output = ExtraPowers<E, U, R>;
+}
+impl<E: Number, U, R> PowerOp<ExtraPowers<E, U, R>, Per> for NoExtra
+where
+    NoExtra: PowerOp<R, Per>,
+{
+    type Output = ExtraPowers<E::Neg, U, <NoExtra as PowerOp<R, Per>>::Output>;
+}
+impl<E1: Add<E2>, U1: EqualsOrZero<U2>, R1: PowerOp<R2, Times>, E2, U2, R2>
+    PowerOp<ExtraPowers<E2, U2, R2>, Times> for ExtraPowers<E1, U1, R1>
+where
+    E1::Output: InRange,
+{
+    type Output = ExtraPowers<E1::Output, <U1 as EqualsOrZero<U2>>::SelfType, R1::Output>;
+}
+impl<E1: Add<E2::Neg>, U1: EqualsOrZero<U2>, R1: PowerOp<R2, Per>, E2: Number, U2, R2>
+    PowerOp<ExtraPowers<E2, U2, R2>, Per> for ExtraPowers<E1, U1, R1>
+where
+    E1::Output: InRange,
+{
+    type Output = ExtraPowers<E1::Output, <U1 as EqualsOrZero<U2>>::SelfType, R1::Output>;
+}
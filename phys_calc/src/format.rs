@@ -0,0 +1,629 @@
+use std::fmt::Display;
+use std::fmt::LowerExp;
+
+use crate::extra::ExtraTrait;
+use crate::math::number;
+use crate::math_helpers::*;
+use crate::prefix::{Prefix, NO_PREFIX};
+use crate::unit::Symbols;
+
+// How the terms of a composite unit are joined: `kg·m·s⁻²` or `kg m/s^2`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Default)]
+pub enum CompositeStyle {
+    #[default]
+    Dot,
+    Slash,
+}
+
+// Which form of the unit symbols to write: `μm`, `um`, `\SI{5}{\micro\metre}`
+// or `5 micrometers`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Default)]
+pub enum SymbolStyle {
+    #[default]
+    Unicode,
+    Ascii,
+    Latex,
+    Name,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum PrefixSystem {
+    Decimal,
+    Binary,
+    // For units a prefix makes no sense on, like °C.
+    Unprefixed,
+}
+
+// One factor of a unit symbol, e.g. the `km²` in `km²·s⁻¹`.
+#[derive(Copy, Clone, Debug)]
+pub struct UnitTerm {
+    pub prefix: Prefix,
+    pub symbols: Symbols,
+    pub exponent: i32,
+}
+
+impl UnitTerm {
+    pub fn new(symbols: Symbols, exponent: i32) -> Self {
+        UnitTerm {
+            prefix: NO_PREFIX,
+            symbols,
+            exponent,
+        }
+    }
+
+    fn symbol(&self, style: SymbolStyle, plural: bool) -> String {
+        match style {
+            SymbolStyle::Unicode => format!("{}{}", self.prefix.symbol, self.symbols.unicode),
+            SymbolStyle::Ascii => format!("{}{}", self.prefix.ascii(), self.symbols.ascii),
+            SymbolStyle::Latex => format!("{}{}", self.prefix.latex(), self.symbols.latex),
+            SymbolStyle::Name => match plural {
+                true => format!("{}{}", self.prefix.name, self.symbols.plural),
+                false => format!("{}{}", self.prefix.name, self.symbols.singular),
+            },
+        }
+    }
+}
+
+// Writes a unit made of `terms`, given in the order they should appear. Positive
+// exponents are expected before negative ones.
+pub fn render_unit(
+    terms: &[UnitTerm],
+    composite: CompositeStyle,
+    style: SymbolStyle,
+    plural: bool,
+) -> String {
+    let (positive, negative): (Vec<&UnitTerm>, Vec<&UnitTerm>) =
+        terms.iter().partition(|t| t.exponent > 0);
+    // Only the last factor of the numerator is pluralized: `kilogram meters per second`.
+    let is_plural = |i: usize| plural && i + 1 == positive.len();
+
+    match (style, composite) {
+        (SymbolStyle::Latex, _) => terms
+            .iter()
+            .map(|t| {
+                let per = if t.exponent < 0 { "\\per" } else { "" };
+                let power = match t.exponent.abs() {
+                    1 => String::new(),
+                    2 => "\\squared".to_string(),
+                    3 => "\\cubed".to_string(),
+                    n => format!("\\tothe{{{n}}}"),
+                };
+                format!("{per}{}{power}", t.symbol(style, false))
+            })
+            .collect(),
+        (SymbolStyle::Name, _) => {
+            let named = |i: usize, t: &UnitTerm| {
+                let name = t.symbol(style, is_plural(i) && t.exponent > 0);
+                match t.exponent.abs() {
+                    1 => name,
+                    2 => format!("{name} squared"),
+                    3 => format!("{name} cubed"),
+                    n => format!("{name} to the power {n}"),
+                }
+            };
+            let numerator = positive.iter().enumerate().map(|(i, t)| named(i, t));
+            let denominator = negative.iter().map(|t| format!("per {}", named(0, t)));
+            numerator.chain(denominator).collect::<Vec<_>>().join(" ")
+        }
+        (_, CompositeStyle::Dot) => {
+            let (joiner, power): (_, fn(i32) -> String) = match style {
+                SymbolStyle::Unicode => ("·", superscript),
+                _ => ("*", caret),
+            };
+            terms
+                .iter()
+                .map(|t| format!("{}{}", t.symbol(style, false), power(t.exponent)))
+                .collect::<Vec<_>>()
+                .join(joiner)
+        }
+        (_, CompositeStyle::Slash) => {
+            let join = |terms: &[&UnitTerm]| {
+                terms
+                    .iter()
+                    .map(|t| format!("{}{}", t.symbol(style, false), caret(t.exponent.abs())))
+                    .collect::<Vec<_>>()
+                    .join(" ")
+            };
+            let numerator = join(&positive);
+            let denominator = join(&negative);
+            match (numerator.is_empty(), negative.len()) {
+                (_, 0) => numerator,
+                (true, 1) => format!("1/{denominator}"),
+                (true, _) => format!("1/({denominator})"),
+                (false, 1) => format!("{numerator}/{denominator}"),
+                (false, _) => format!("{numerator}/({denominator})"),
+            }
+        }
+    }
+}
+
+// Puts a formatted number and its unit together. Symbols follow the number
+// directly (`5km`) unless `spaced`, names are always separated (`5 kilometers`).
+fn join_value(number: &str, unit: &str, style: SymbolStyle, spaced: bool) -> String {
+    match style {
+        _ if unit.is_empty() => number.to_string(),
+        SymbolStyle::Latex => format!("\\SI{{{number}}}{{{unit}}}"),
+        SymbolStyle::Name => format!("{number} {unit}"),
+        _ if spaced => format!("{number} {unit}"),
+        // `5/s` rather than `51/s`.
+        _ => match unit.strip_prefix('1') {
+            Some(rest) if rest.starts_with('/') => format!("{number}{rest}"),
+            _ => format!("{number}{unit}"),
+        },
+    }
+}
+
+// A value together with its unit, written in a chosen `SymbolStyle`.
+#[derive(Clone, Debug)]
+pub struct Styled {
+    value: number,
+    terms: Vec<UnitTerm>,
+    composite: CompositeStyle,
+    style: SymbolStyle,
+}
+
+impl Styled {
+    pub fn new(value: number, terms: Vec<UnitTerm>, style: SymbolStyle) -> Self {
+        Styled {
+            value,
+            terms,
+            composite: CompositeStyle::Dot,
+            style,
+        }
+    }
+
+    pub fn composite(self, composite: CompositeStyle) -> Self {
+        Styled { composite, ..self }
+    }
+}
+
+impl Display for Styled {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let number = match f.precision() {
+            Some(precision) => format!("{:.precision$}", self.value),
+            Option::None => self.value.to_string(),
+        };
+        let unit = render_unit(
+            &self.terms,
+            self.composite,
+            self.style,
+            self.value.abs() != 1.,
+        );
+        f.write_str(&join_value(&number, &unit, self.style, false))
+    }
+}
+
+// Displays a value with the prefix that best fits its magnitude, e.g. `123 nm`.
+// The prefix is attached to the first term.
+#[derive(Clone, Debug)]
+pub struct Engineering {
+    value: number,
+    terms: Vec<UnitTerm>,
+    system: PrefixSystem,
+    style: SymbolStyle,
+    sig_figs: usize,
+}
+
+impl Engineering {
+    pub fn new(value: number, symbols: Symbols) -> Self {
+        Engineering::composite(value, vec![UnitTerm::new(symbols, 1)])
+    }
+
+    pub fn composite(value: number, terms: Vec<UnitTerm>) -> Self {
+        Engineering {
+            value,
+            terms,
+            system: PrefixSystem::Decimal,
+            style: SymbolStyle::Unicode,
+            sig_figs: 3,
+        }
+    }
+
+    pub fn sig_figs(self, sig_figs: usize) -> Self {
+        Engineering {
+            sig_figs: sig_figs.max(1),
+            ..self
+        }
+    }
+
+    pub fn binary(self) -> Self {
+        Engineering {
+            system: PrefixSystem::Binary,
+            ..self
+        }
+    }
+
+    pub fn unprefixed(self) -> Self {
+        Engineering {
+            system: PrefixSystem::Unprefixed,
+            ..self
+        }
+    }
+
+    pub fn style(self, style: SymbolStyle) -> Self {
+        Engineering { style, ..self }
+    }
+}
+
+impl Display for Engineering {
+    // The formatter's precision overrides the number of significant figures,
+    // and `{:#}` keeps trailing zeros (`4.70 kΩ` instead of `4.7 kΩ`).
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let sig_figs = f.precision().unwrap_or(self.sig_figs).max(1);
+        let keep_zeros = f.alternate();
+        let mut terms = self.terms.clone();
+
+        // Plain fractions have no symbol to put a prefix on.
+        let number = match terms
+            .first_mut()
+            .filter(|first| !first.symbols.unicode.is_empty())
+        {
+            _ if !self.value.is_finite() => self.value.to_string(),
+            Some(first) if self.value != 0. => match self.system {
+                PrefixSystem::Decimal => {
+                    let (digits, exp) = significant(self.value, sig_figs);
+                    // Step in thousands of the unit raised to its exponent, so
+                    // that e.g. 1e6 m² becomes 1 km² and 2.5e9 s⁻¹ becomes 2.5 ns⁻¹.
+                    let step = 3 * first.exponent.abs();
+                    let k = (exp.div_euclid(step) * first.exponent.signum()).clamp(-10, 10);
+                    let shift = exp - 3 * k * first.exponent;
+                    first.prefix = Prefix::si(3 * k).unwrap_or(NO_PREFIX);
+                    place(self.value, &digits, shift, keep_zeros)
+                }
+                PrefixSystem::Binary => {
+                    let mut k = if self.value.abs() < 1. {
+                        0
+                    } else {
+                        ((self.value.abs().log2() / 10.).floor() as i32).clamp(0, 8)
+                    };
+                    let mut scaled = self.value / 1024f64.powi(k);
+                    let mut rounded = significant(scaled, sig_figs);
+                    // Like the decimal prefixes, keep the mantissa below 1000
+                    // once rounded: 1023.99 B is 1 KiB rather than 1020 B.
+                    while rounded.1 >= 3 && k < 8 {
+                        k += 1;
+                        scaled = self.value / 1024f64.powi(k);
+                        rounded = significant(scaled, sig_figs);
+                    }
+                    first.prefix = Prefix::binary(k).unwrap_or(NO_PREFIX);
+                    place(scaled, &rounded.0, rounded.1, keep_zeros)
+                }
+                PrefixSystem::Unprefixed => {
+                    let (digits, exp) = significant(self.value, sig_figs);
+                    place(self.value, &digits, exp, keep_zeros)
+                }
+            },
+            _ => {
+                let (digits, exp) = significant(self.value, sig_figs);
+                place(self.value, &digits, exp, keep_zeros)
+            }
+        };
+
+        let plural = number.trim_start_matches('-') != "1";
+        let unit = render_unit(&terms, CompositeStyle::Dot, self.style, plural);
+        f.write_str(&join_value(&number, &unit, self.style, true))
+    }
+}
+
+// Rounds `value` to `sig_figs` significant digits, returning the digits and the
+// decimal exponent of the first one. Going through `{:e}` keeps the rounding exact.
+fn significant(value: number, sig_figs: usize) -> (String, i32) {
+    let formatted = format!("{:.*e}", sig_figs - 1, value.abs());
+    let (mantissa, exp) = formatted
+        .split_once('e')
+        .expect("`{:e}` always produces an exponent");
+    (
+        mantissa.replace('.', ""),
+        exp.parse()
+            .expect("`{:e}` always produces an integer exponent"),
+    )
+}
+
+// Writes `digits` with the decimal point placed after the digit for `10^exp`.
+fn place(value: number, digits: &str, exp: i32, keep_zeros: bool) -> String {
+    let mut out = String::new();
+    if value.is_sign_negative() && value != 0. {
+        out.push('-');
+    }
+    let int_len = exp + 1;
+    if int_len <= 0 {
+        out.push_str("0.");
+        out.extend(std::iter::repeat_n('0', (-int_len) as usize));
+        out.push_str(digits);
+    } else if int_len as usize >= digits.len() {
+        out.push_str(digits);
+        out.extend(std::iter::repeat_n('0', int_len as usize - digits.len()));
+    } else {
+        let (int, frac) = digits.split_at(int_len as usize);
+        out.push_str(int);
+        out.push('.');
+        out.push_str(frac);
+    }
+    if !keep_zeros && out.contains('.') {
+        out.truncate(out.trim_end_matches('0').trim_end_matches('.').len());
+    }
+    out
+}
+
+fn caret(exponent: i32) -> String {
+    match exponent {
+        1 => String::new(),
+        _ => format!("^{exponent}"),
+    }
+}
+
+pub(crate) fn superscript(exponent: i32) -> String {
+    if exponent == 1 {
+        return String::new();
+    }
+    exponent
+        .to_string()
+        .chars()
+        .map(|c| match c {
+            '-' => '⁻',
+            '0' => '⁰',
+            '1' => '¹',
+            '2' => '²',
+            '3' => '³',
+            '4' => '⁴',
+            '5' => '⁵',
+            '6' => '⁶',
+            '7' => '⁷',
+            '8' => '⁸',
+            '9' => '⁹',
+            _ => unreachable!(),
+        })
+        .collect()
+}
+
+impl<
+        L: LengthTrait,
+        T: TimeTrait,
+        TMP: TempTrait,
+        M: MassTrait,
+        C: CurrentTrait,
+        LI: LuminousIntensityTrait,
+        Q: QuantityTrait,
+        A: AngleTrait,
+        S: SolidAngleTrait,
+        D: DigitalInformationTrait,
+        X: ExtraTrait,
+    > Derived<L, T, TMP, M, C, LI, Q, A, S, D, X>
+{
+    // Non-zero terms in display order: conventional order, positive exponents first.
+    fn unit_terms(reference: bool) -> Vec<UnitTerm> {
+        let mut terms = Self::terms();
+        terms.sort_by_key(|term| term.exponent < 0);
+        terms
+            .iter()
+            .map(|term| match reference {
+                true => UnitTerm::new(term.reference_symbols, term.exponent),
+                false => UnitTerm::new(term.symbols, term.exponent),
+            })
+            .collect()
+    }
+
+    pub fn unit() -> String {
+        Self::unit_with(CompositeStyle::Dot)
+    }
+
+    pub fn unit_with(composite: CompositeStyle) -> String {
+        Self::symbol(composite, SymbolStyle::Unicode)
+    }
+
+    pub fn symbol(composite: CompositeStyle, style: SymbolStyle) -> String {
+        render_unit(&Self::unit_terms(false), composite, style, false)
+    }
+
+    pub fn styled(&self, style: SymbolStyle) -> Styled {
+        Styled::new(self.inner, Self::unit_terms(false), style)
+    }
+}
+
+impl<
+        L: LengthTrait,
+        T: TimeTrait,
+        TMP: LinearTemp,
+        M: MassTrait,
+        C: CurrentTrait,
+        LI: LuminousIntensityTrait,
+        Q: QuantityTrait,
+        A: AngleTrait,
+        S: SolidAngleTrait,
+        D: DigitalInformationTrait,
+        X: ExtraTrait,
+    > Derived<L, T, TMP, M, C, LI, Q, A, S, D, X>
+{
+    // Expressed in coherent SI units (kg·m·s⁻²), so only without an offset in
+    // the temperature slot.
+    pub fn engineering(&self) -> Engineering {
+        let value = Self::terms().iter().fold(self.inner, |value, term| {
+            value * (term.mult / term.reference_mult).powi(term.exponent)
+        });
+        Engineering::composite(value, Self::unit_terms(true))
+    }
+}
+
+// `{:#}` switches to the slash style.
+impl<
+        L: LengthTrait,
+        T: TimeTrait,
+        TMP: TempTrait,
+        M: MassTrait,
+        C: CurrentTrait,
+        LI: LuminousIntensityTrait,
+        Q: QuantityTrait,
+        A: AngleTrait,
+        S: SolidAngleTrait,
+        D: DigitalInformationTrait,
+        X: ExtraTrait,
+    > Display for Derived<L, T, TMP, M, C, LI, Q, A, S, D, X>
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let composite = match f.alternate() {
+            true => CompositeStyle::Slash,
+            false => CompositeStyle::Dot,
+        };
+        let unit = Self::unit_with(composite);
+        f.write_str(&join_value(
+            &self.inner.to_string(),
+            &unit,
+            SymbolStyle::Unicode,
+            false,
+        ))
+    }
+}
+
+impl<
+        L: LengthTrait,
+        T: TimeTrait,
+        TMP: TempTrait,
+        M: MassTrait,
+        C: CurrentTrait,
+        LI: LuminousIntensityTrait,
+        Q: QuantityTrait,
+        A: AngleTrait,
+        S: SolidAngleTrait,
+        D: DigitalInformationTrait,
+        X: ExtraTrait,
+    > LowerExp for Derived<L, T, TMP, M, C, LI, Q, A, S, D, X>
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let composite = match f.alternate() {
+            true => CompositeStyle::Slash,
+            false => CompositeStyle::Dot,
+        };
+        let unit = Self::unit_with(composite);
+        f.write_str(&join_value(
+            &format!("{:e}", self.inner),
+            &unit,
+            SymbolStyle::Unicode,
+            false,
+        ))
+    }
+}
+
+macro_rules! impl_format {
+    // Prefixed as the `Derived` the dimension converts through, so the prefix
+    // goes on the base unit and follows its power: 1e6 m² is 1 km².
+    (@engineering $struct_name:ident<$generic:ident : $trait:ident>, derived) => {
+        pub fn engineering(&self) -> $crate::format::Engineering {
+            UnitToDerived::to_derived(*self).engineering()
+        }
+    };
+    (@engineering $struct_name:ident<$generic:ident : $trait:ident>, $reference:ident) => {
+        pub fn engineering(&self) -> $crate::format::Engineering {
+            // Units with an offset (°C, °F) stay as they are, in K the
+            // reader would see a different number.
+            if $generic::to_base($struct_name::new(0.)).inner != 0. {
+                return $crate::format::Engineering::new(self.inner, $generic::symbols())
+                    .unprefixed();
+            }
+            $crate::format::Engineering::new(
+                self.convert::<$reference>().inner,
+                <$reference as $trait>::symbols(),
+            )
+        }
+    };
+    ($struct_name:ident<$generic:ident : $trait:ident>, $reference:ident) => {
+        impl<$generic: $trait> $struct_name<$generic> {
+            $crate::format::impl_format!(@engineering $struct_name<$generic: $trait>, $reference);
+
+            pub fn styled(&self, style: $crate::format::SymbolStyle) -> $crate::format::Styled {
+                $crate::format::Styled::new(
+                    self.inner,
+                    vec![$crate::format::UnitTerm::new($generic::symbols(), 1)],
+                    style,
+                )
+            }
+        }
+    };
+}
+
+pub(crate) use impl_format;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BYTE: Symbols = Symbols {
+        unicode: "B",
+        ascii: "B",
+        latex: r"\byte",
+        singular: "byte",
+        plural: "bytes",
+    };
+
+    fn eng(value: number) -> String {
+        Engineering::new(value, BYTE).to_string()
+    }
+
+    fn binary(value: number) -> String {
+        Engineering::new(value, BYTE).binary().to_string()
+    }
+
+    #[test]
+    fn rounding() {
+        assert_eq!(significant(1234.5, 3), ("123".to_string(), 3));
+        assert_eq!(significant(0.0012345, 2), ("12".to_string(), -3));
+        assert_eq!(significant(9.996, 3), ("100".to_string(), 1));
+        assert_eq!(place(-1.5, "150", 0, false), "-1.5");
+        assert_eq!(place(-1.5, "150", 0, true), "-1.50");
+        assert_eq!(place(0.012, "12", -2, false), "0.012");
+        assert_eq!(place(1200., "12", 3, false), "1200");
+    }
+
+    #[test]
+    fn decimal_prefixes() {
+        assert_eq!(eng(1234.), "1.23 kB");
+        assert_eq!(eng(999.9), "1 kB");
+        assert_eq!(eng(0.000123), "123 μB");
+        assert_eq!(eng(-4700.), "-4.7 kB");
+        assert_eq!(format!("{:#}", Engineering::new(4700., BYTE)), "4.70 kB");
+        assert_eq!(
+            format!("{:.5}", Engineering::new(123456., BYTE)),
+            "123.46 kB"
+        );
+        assert_eq!(eng(0.), "0 B");
+        assert_eq!(eng(number::INFINITY), "inf B");
+        assert_eq!(eng(number::NAN), "NaN B");
+    }
+
+    #[test]
+    fn binary_prefixes() {
+        assert_eq!(binary(1023.99), "1 KiB");
+        assert_eq!(binary(1000.), "0.977 KiB");
+        assert_eq!(binary(999.), "999 B");
+        assert_eq!(binary(1536.), "1.5 KiB");
+        assert_eq!(binary(1048575.), "1 MiB");
+        assert_eq!(binary(0.5), "0.5 B");
+        assert_eq!(binary(0.), "0 B");
+    }
+
+    #[test]
+    fn affine_units_keep_their_unit() {
+        use crate::base::temperature::{Celsius, Fahrenheit, Kelvin, Temperature};
+        assert_eq!(
+            Temperature::<Celsius>::new(21.5).engineering().to_string(),
+            "21.5 °C"
+        );
+        assert_eq!(
+            Temperature::<Fahrenheit>::new(-40.)
+                .engineering()
+                .to_string(),
+            "-40 °F"
+        );
+        assert_eq!(
+            Temperature::<Kelvin>::new(0.0015).engineering().to_string(),
+            "1.5 mK"
+        );
+    }
+
+    #[test]
+    fn unprefixed() {
+        assert_eq!(
+            Engineering::new(1234.5, BYTE).unprefixed().to_string(),
+            "1230 B"
+        );
+    }
+}
@@ -1,9 +1,27 @@
 // pub mod type_level_math;
 pub mod base;
+pub mod compound;
+pub mod derived;
+mod dimension;
+pub mod extra;
+pub mod format;
+pub mod literals;
 pub mod math;
 pub mod math_helpers;
 pub mod math_impl;
+pub mod prefix;
 pub mod specialization;
 
 pub mod unit;
+pub use phys_calc_macros::{q, Unit};
 pub use unit::Unit;
+
+// Lets macros emit `::phys_calc::...` paths that also resolve inside this crate.
+// `#[derive(Unit)]` and `q!` expand to these, so they are part of the public
+// surface: `Unit`, `base::unit_traits::*`, `base::<dimension>::<Quantity>::new`,
+// `unit::{Symbols, None, LinearUnit, Prefixable}`,
+// `math::{number, EqualsOrZero}`, the exponents in `math`,
+// `math_helpers::{Derived, MulHelper, DivHelper, *Power}`,
+// `math_helpers::{DimensionOp, Times, Per, compound_term, UnitToDerived}`,
+// `literals::*` and `extra::{ExtraUnit, Extra}`.
+extern crate self as phys_calc;
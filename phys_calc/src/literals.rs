@@ -0,0 +1,20 @@
+// `use phys_calc::literals::*;` brings every unit's literal trait into scope,
+// along with the units and quantities themselves: `5.0.km()`, `250.ms()`.
+pub use crate::base::angle::*;
+pub use crate::base::current::*;
+pub use crate::base::digital_information::*;
+pub use crate::base::length::*;
+pub use crate::base::luminous_intensity::*;
+pub use crate::base::mass::*;
+pub use crate::base::quantity::*;
+pub use crate::base::solid_angle::*;
+pub use crate::base::temperature::*;
+pub use crate::base::time::*;
+pub use crate::derived::acceleration::*;
+pub use crate::derived::angular_velocity::*;
+pub use crate::derived::area::*;
+pub use crate::derived::data_rate::*;
+pub use crate::derived::frequency::*;
+pub use crate::derived::ratio::*;
+pub use crate::derived::velocity::*;
+pub use crate::derived::volume::*;
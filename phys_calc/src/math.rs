@@ -28,11 +28,20 @@ pub struct Five;
 #[derive(Clone, Copy, Debug)]
 pub struct Invalid;
 
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` and `{T}` are different units of the same dimension",
+    label = "expected `{Self}` here",
+    note = "convert one side first, e.g. with `.convert::<{Self}>()`"
+)]
 pub trait EqualsOrZero<T = Self> {
     type SelfType;
 }
 // impl<T> Equals<T> for T {}
 
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` is not a type-level exponent",
+    note = "exponents are the types `NegFive` through `Five` in `phys_calc::math`"
+)]
 pub trait Number:
     Copy
     + Add<NegFive>
@@ -49,9 +58,30 @@ pub trait Number:
     + Add<Invalid>
 {
     type Neg: Number;
+    const VALUE: i32;
     fn check() {}
 }
 
+// What `Add` is allowed to return, `Invalid` is the result of leaving -5..=5.
+#[diagnostic::on_unimplemented(
+    message = "exponent overflow",
+    label = "this raises a dimension past the fifth power",
+    note = "exponents of a quantity have to stay between -5 and 5"
+)]
+pub trait InRange: Number {}
+
+impl InRange for NegFive {}
+impl InRange for NegFour {}
+impl InRange for NegThree {}
+impl InRange for NegTwo {}
+impl InRange for NegOne {}
+impl InRange for Zero {}
+impl InRange for One {}
+impl InRange for Two {}
+impl InRange for Three {}
+impl InRange for Four {}
+impl InRange for Five {}
+
 macro_rules! impl_math {
     ($lhs:ident, $rhs:ident, $res:ident) => {
         impl Add<$rhs> for $lhs {
@@ -65,39 +95,52 @@ macro_rules! impl_math {
 
 impl Number for NegFive {
     type Neg = Five;
+    const VALUE: i32 = -5;
 }
 impl Number for NegFour {
     type Neg = Four;
+    const VALUE: i32 = -4;
 }
 impl Number for NegThree {
     type Neg = Three;
+    const VALUE: i32 = -3;
 }
 impl Number for NegTwo {
     type Neg = Two;
+    const VALUE: i32 = -2;
 }
 impl Number for NegOne {
     type Neg = One;
+    const VALUE: i32 = -1;
 }
 impl Number for Zero {
     type Neg = Zero;
+    const VALUE: i32 = 0;
 }
 impl Number for One {
     type Neg = NegOne;
+    const VALUE: i32 = 1;
 }
 impl Number for Two {
     type Neg = NegTwo;
+    const VALUE: i32 = 2;
 }
 impl Number for Three {
     type Neg = NegThree;
+    const VALUE: i32 = 3;
 }
 impl Number for Four {
     type Neg = NegFour;
+    const VALUE: i32 = 4;
 }
 impl Number for Five {
     type Neg = NegFive;
+    const VALUE: i32 = 5;
 }
 impl Number for Invalid {
     type Neg = Invalid;
+    // Only evaluated when used, which turns reading an overflowed exponent into a compile error.
+    const VALUE: i32 = panic!("exponent out of range");
 }
 
 /* Generated via python script:
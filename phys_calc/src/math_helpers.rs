@@ -1,66 +1,266 @@
 use std::marker::PhantomData;
 use std::ops::Add;
 
+use crate::base::angle::Radian;
+use crate::base::current::Ampere;
+use crate::base::digital_information::Byte;
+use crate::base::length::Meter;
+use crate::base::luminous_intensity::Candela;
+use crate::base::mass::Gram;
+use crate::base::quantity::Mole;
+use crate::base::solid_angle::Steradian;
+use crate::base::temperature::Kelvin;
+use crate::base::time::Second;
 use crate::base::unit_traits::*;
+use crate::extra::*;
 use crate::math::*;
+use crate::math_impl::impl_math;
+use crate::unit::{LinearUnit, Symbols};
 
+#[diagnostic::on_unimplemented(
+    message = "cannot multiply `{Self}` by `{T}`",
+    note = "both sides have to be quantities, e.g. `Length<Meter>` or `Derived<...>`"
+)]
 pub trait MulHelper<T> {
     type Output;
     fn multiply(self, rhs: T) -> Self::Output;
 }
 
+#[diagnostic::on_unimplemented(
+    message = "cannot divide `{Self}` by `{T}`",
+    note = "both sides have to be quantities, e.g. `Length<Meter>` or `Derived<...>`"
+)]
 pub trait DivHelper<T> {
     type Output;
     fn divide(self, rhs: T) -> Self::Output;
 }
 
-pub trait TimeTrait {}
-pub struct TimePower<T: Number, U: TimeUnit>(PhantomData<(T, U)>);
+pub trait TimeTrait: Power {}
+pub struct TimePower<T, U>(PhantomData<(T, U)>);
 impl<T: Number, U: TimeUnit> TimeTrait for TimePower<T, U> {}
 
-pub trait LengthTrait {}
-pub struct LengthPower<L: Number, U: LengthUnit>(PhantomData<(L, U)>);
+pub trait LengthTrait: Power {}
+pub struct LengthPower<L, U>(PhantomData<(L, U)>);
 impl<L: Number, U: LengthUnit> LengthTrait for LengthPower<L, U> {}
 
-pub trait TempTrait {}
-pub struct TempPower<TMP: Number, U: TempUnit>(PhantomData<(TMP, U)>);
+pub trait TempTrait: Power {}
+pub struct TempPower<TMP, U>(PhantomData<(TMP, U)>);
 impl<TMP: Number, U: TempUnit> TempTrait for TempPower<TMP, U> {}
 
-pub trait MassTrait {}
-pub struct MassPower<M: Number, U: MassUnit>(PhantomData<(M, U)>);
+// A temperature slot whose unit has a factor to K, not °C or °F.
+pub trait LinearTemp: TempTrait {}
+impl<TMP: Number, U: TempUnit + LinearUnit> LinearTemp for TempPower<TMP, U> {}
+
+pub trait MassTrait: Power {}
+pub struct MassPower<M, U>(PhantomData<(M, U)>);
 impl<M: Number, U: MassUnit> MassTrait for MassPower<M, U> {}
 
-pub trait CurrentTrait {}
-pub struct CurrentPower<M: Number, U: CurrentUnit>(PhantomData<(M, U)>);
+pub trait CurrentTrait: Power {}
+pub struct CurrentPower<M, U>(PhantomData<(M, U)>);
 impl<C: Number, U: CurrentUnit> CurrentTrait for CurrentPower<C, U> {}
 
-pub trait LuminousIntensityTrait {}
-pub struct LuminousIntensityPower<M: Number, U: LuminousIntensityUnit>(PhantomData<(M, U)>);
+pub trait LuminousIntensityTrait: Power {}
+pub struct LuminousIntensityPower<M, U>(PhantomData<(M, U)>);
 impl<LI: Number, U: LuminousIntensityUnit> LuminousIntensityTrait
     for LuminousIntensityPower<LI, U>
 {
 }
 
-pub trait QuantityTrait {}
-pub struct QuantityPower<M: Number, U: QuantityUnit>(PhantomData<(M, U)>);
+pub trait QuantityTrait: Power {}
+pub struct QuantityPower<M, U>(PhantomData<(M, U)>);
 impl<Q: Number, U: QuantityUnit> QuantityTrait for QuantityPower<Q, U> {}
 
-pub trait AngleTrait {}
-pub struct AnglePower<M: Number, U: AngleUnit>(PhantomData<(M, U)>);
+pub trait AngleTrait: Power {}
+pub struct AnglePower<M, U>(PhantomData<(M, U)>);
 impl<A: Number, U: AngleUnit> AngleTrait for AnglePower<A, U> {}
 
-pub trait SolidAngleTrait {}
-pub struct SolidAnglePower<M: Number, U: SolidAngleUnit>(PhantomData<(M, U)>);
+pub trait SolidAngleTrait: Power {}
+pub struct SolidAnglePower<M, U>(PhantomData<(M, U)>);
 impl<S: Number, U: SolidAngleUnit> SolidAngleTrait for SolidAnglePower<S, U> {}
 
-pub trait DigitalInformationTrait {}
-pub struct DigitalInformationPower<M: Number, U: DigitalInformationUnit>(PhantomData<(M, U)>);
+pub trait DigitalInformationTrait: Power {}
+pub struct DigitalInformationPower<M, U>(PhantomData<(M, U)>);
 impl<D: Number, U: DigitalInformationUnit> DigitalInformationTrait
     for DigitalInformationPower<D, U>
 {
 }
 
-pub struct Derived<
+// Runtime view of one `XPower<Exp, Unit>` slot of a `Derived`.
+pub trait Power {
+    fn term() -> Option<Term>;
+}
+
+#[derive(Copy, Clone, Debug)]
+pub struct Term {
+    pub exponent: i32,
+    pub mult: number,
+    pub symbols: Symbols,
+    // The unit SI prefixes attach to for this dimension (gram rather than kilogram).
+    pub reference_symbols: Symbols,
+    pub reference_mult: number,
+}
+
+macro_rules! impl_power {
+    ($power:ident<$unit_trait:ident>, $reference:ident) => {
+        impl<E: Number, U: $unit_trait> Power for $power<E, U> {
+            fn term() -> Option<Term> {
+                // Zero-exponent slots may hold `None`, whose methods panic.
+                if E::VALUE == 0 {
+                    return Option::None;
+                }
+                Some(Term {
+                    exponent: E::VALUE,
+                    mult: U::mult(),
+                    symbols: U::symbols(),
+                    reference_symbols: <$reference as $unit_trait>::symbols(),
+                    reference_mult: <$reference as $unit_trait>::mult(),
+                })
+            }
+        }
+
+        impl<E1: Add<E2>, U1: EqualsOrZero<U2>, E2, U2> PowerOp<$power<E2, U2>, Times>
+            for $power<E1, U1>
+        where
+            E1::Output: InRange,
+        {
+            type Output = $power<E1::Output, <U1 as EqualsOrZero<U2>>::SelfType>;
+        }
+
+        impl<E1: Add<E2::Neg>, U1: EqualsOrZero<U2>, E2: Number, U2> PowerOp<$power<E2, U2>, Per>
+            for $power<E1, U1>
+        where
+            E1::Output: InRange,
+        {
+            type Output = $power<E1::Output, <U1 as EqualsOrZero<U2>>::SelfType>;
+        }
+    };
+}
+
+impl_power!(LengthPower<LengthUnit>, Meter);
+impl_power!(TimePower<TimeUnit>, Second);
+impl_power!(TempPower<TempUnit>, Kelvin);
+impl_power!(MassPower<MassUnit>, Gram);
+impl_power!(CurrentPower<CurrentUnit>, Ampere);
+impl_power!(LuminousIntensityPower<LuminousIntensityUnit>, Candela);
+impl_power!(QuantityPower<QuantityUnit>, Mole);
+impl_power!(AnglePower<AngleUnit>, Radian);
+impl_power!(SolidAnglePower<SolidAngleUnit>, Steradian);
+impl_power!(DigitalInformationPower<DigitalInformationUnit>, Byte);
+
+pub struct Derived<L, T, TMP, M, C, LI, Q, A, S, D, X = NoExtra> {
+    // I can't think of any actually good way to make this simpler
+    // If anyone can, feel free to PR
+    #[allow(clippy::type_complexity)]
+    pub(crate) types: PhantomData<(T, L, TMP, M, C, LI, Q, A, S, D, X)>,
+    pub(crate) inner: number,
+}
+
+impl<
+        L: LengthTrait,
+        T: TimeTrait,
+        TMP: TempTrait,
+        M: MassTrait,
+        C: CurrentTrait,
+        LI: LuminousIntensityTrait,
+        Q: QuantityTrait,
+        A: AngleTrait,
+        S: SolidAngleTrait,
+        D: DigitalInformationTrait,
+        X: ExtraTrait,
+    > Derived<L, T, TMP, M, C, LI, Q, A, S, D, X>
+{
+    pub const ZERO: Self = Self::new(0.);
+
+    pub const fn new(value: number) -> Self {
+        Derived {
+            inner: value,
+            types: PhantomData,
+        }
+    }
+
+    pub const fn value(&self) -> number {
+        self.inner
+    }
+}
+
+impl<
+        L: LengthTrait,
+        T: TimeTrait,
+        TMP: TempTrait,
+        M: MassTrait,
+        C: CurrentTrait,
+        LI: LuminousIntensityTrait,
+        Q: QuantityTrait,
+        A: AngleTrait,
+        S: SolidAngleTrait,
+        D: DigitalInformationTrait,
+        X: ExtraTrait,
+    > Default for Derived<L, T, TMP, M, C, LI, Q, A, S, D, X>
+{
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
+// Derived by hand, `derive` would require the marker types to be `Clone` too.
+impl<
+        L: LengthTrait,
+        T: TimeTrait,
+        TMP: TempTrait,
+        M: MassTrait,
+        C: CurrentTrait,
+        LI: LuminousIntensityTrait,
+        Q: QuantityTrait,
+        A: AngleTrait,
+        S: SolidAngleTrait,
+        D: DigitalInformationTrait,
+        X: ExtraTrait,
+    > Clone for Derived<L, T, TMP, M, C, LI, Q, A, S, D, X>
+{
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<
+        L: LengthTrait,
+        T: TimeTrait,
+        TMP: TempTrait,
+        M: MassTrait,
+        C: CurrentTrait,
+        LI: LuminousIntensityTrait,
+        Q: QuantityTrait,
+        A: AngleTrait,
+        S: SolidAngleTrait,
+        D: DigitalInformationTrait,
+        X: ExtraTrait,
+    > Copy for Derived<L, T, TMP, M, C, LI, Q, A, S, D, X>
+{
+}
+
+impl<
+        L: LengthTrait,
+        T: TimeTrait,
+        TMP: TempTrait,
+        M: MassTrait,
+        C: CurrentTrait,
+        LI: LuminousIntensityTrait,
+        Q: QuantityTrait,
+        A: AngleTrait,
+        S: SolidAngleTrait,
+        D: DigitalInformationTrait,
+        X: ExtraTrait,
+    > std::fmt::Debug for Derived<L, T, TMP, M, C, LI, Q, A, S, D, X>
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Derived")
+            .field("inner", &self.inner)
+            .field("unit", &Self::unit())
+            .finish()
+    }
+}
+
+impl_math!(Derived<
     L: LengthTrait,
     T: TimeTrait,
     TMP: TempTrait,
@@ -71,358 +271,406 @@ pub struct Derived<
     A: AngleTrait,
     S: SolidAngleTrait,
     D: DigitalInformationTrait,
-> {
-    // I can't think of any actually good way to make this simpler
-    // If anyone can, feel free to PR
-    #[allow(clippy::type_complexity)]
-    pub(crate) types: PhantomData<(T, L, TMP, M, C, LI, Q, A, S, D)>,
-    pub inner: number,
+    X: ExtraTrait
+>);
+
+// Dimensionless values multiply without changing type, whatever units they
+// are written in (m/km, ...), since the factors cancel out per slot.
+impl<
+        L: LengthUnit,
+        T: TimeUnit,
+        TMP: TempUnit,
+        M: MassUnit,
+        C: CurrentUnit,
+        LI: LuminousIntensityUnit,
+        Q: QuantityUnit,
+        A: AngleUnit,
+        S: SolidAngleUnit,
+        D: DigitalInformationUnit,
+    > std::iter::Product
+    for Derived<
+        LengthPower<Zero, L>,
+        TimePower<Zero, T>,
+        TempPower<Zero, TMP>,
+        MassPower<Zero, M>,
+        CurrentPower<Zero, C>,
+        LuminousIntensityPower<Zero, LI>,
+        QuantityPower<Zero, Q>,
+        AnglePower<Zero, A>,
+        SolidAnglePower<Zero, S>,
+        DigitalInformationPower<Zero, D>,
+    >
+{
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        Derived {
+            inner: iter.map(|x| x.inner).product(),
+            types: PhantomData,
+        }
+    }
 }
 
-pub trait UnitToDerived {
-    type LengthExp: Number;
-    type TimeExp: Number;
-    type TempExp: Number;
-    type MassExp: Number;
-    type CurrentExp: Number;
-    type LuminousIntensityExp: Number;
-    type QuantityExp: Number;
-    type AngleExp: Number;
-    type SolidAngleExp: Number;
-    type DigitalInformationExp: Number;
-
-    type LengthUnit: LengthUnit;
-    type TimeUnit: TimeUnit;
-    type TempUnit: TempUnit;
-    type MassUnit: MassUnit;
-    type CurrentUnit: CurrentUnit;
-    type LuminousIntensityUnit: LuminousIntensityUnit;
-    type QuantityUnit: QuantityUnit;
-    type AngleUnit: AngleUnit;
-    type SolidAngleUnit: SolidAngleUnit;
-    type DigitalInformationUnit: DigitalInformationUnit;
+impl<
+        L: LengthTrait,
+        T: TimeTrait,
+        TMP: TempTrait,
+        M: MassTrait,
+        C: CurrentTrait,
+        LI: LuminousIntensityTrait,
+        Q: QuantityTrait,
+        A: AngleTrait,
+        S: SolidAngleTrait,
+        D: DigitalInformationTrait,
+        X: ExtraTrait,
+    > Derived<L, T, TMP, M, C, LI, Q, A, S, D, X>
+{
+    // Non-zero slots in conventional order (kg·m·s⁻²): mass first, then the
+    // remaining SI base dimensions, then the convenience dimensions.
+    pub fn terms() -> Vec<Term> {
+        [
+            M::term(),
+            L::term(),
+            T::term(),
+            C::term(),
+            TMP::term(),
+            Q::term(),
+            LI::term(),
+            A::term(),
+            S::term(),
+            D::term(),
+        ]
+        .into_iter()
+        .flatten()
+        .chain(X::terms())
+        .collect()
+    }
 
-    // I can't think of any actually good way to make this simpler
-    // If anyone can, feel free to PR
-    #[allow(clippy::type_complexity)]
-    fn to_derived(
-        self,
-    ) -> Derived<
-        LengthPower<Self::LengthExp, Self::LengthUnit>,
-        TimePower<Self::TimeExp, Self::TimeUnit>,
-        TempPower<Self::TempExp, Self::TempUnit>,
-        MassPower<Self::MassExp, Self::MassUnit>,
-        CurrentPower<Self::CurrentExp, Self::CurrentUnit>,
-        LuminousIntensityPower<Self::LuminousIntensityExp, Self::LuminousIntensityUnit>,
-        QuantityPower<Self::QuantityExp, Self::QuantityUnit>,
-        AnglePower<Self::AngleExp, Self::AngleUnit>,
-        SolidAnglePower<Self::SolidAngleExp, Self::SolidAngleUnit>,
-        DigitalInformationPower<Self::DigitalInformationExp, Self::DigitalInformationUnit>,
+    // The same value in other units of the same dimension, e.g. km/h as m/s.
+    pub fn convert<Rhs: ConvertFrom<Self>>(self) -> Rhs {
+        Rhs::convert_from(self)
+    }
+
+    // `abs` is in the units of `self`.
+    pub fn approx_eq<Rhs>(&self, other: &Rhs, rel: number, abs: number) -> bool
+    where
+        TMP: LinearTemp,
+        Self: SameDimension<Rhs>,
+    {
+        let other = Self::other_base_value(other) / Self::base_factor();
+        crate::math_impl::approx_eq(self.inner, other, rel, abs)
+    }
+}
+
+// Only without an offset in the temperature slot, 20 °C isn't 20 K.
+impl<
+        L: LengthTrait,
+        T: TimeTrait,
+        TMP: LinearTemp,
+        M: MassTrait,
+        C: CurrentTrait,
+        LI: LuminousIntensityTrait,
+        Q: QuantityTrait,
+        A: AngleTrait,
+        S: SolidAngleTrait,
+        D: DigitalInformationTrait,
+        X: ExtraTrait,
+    > Derived<L, T, TMP, M, C, LI, Q, A, S, D, X>
+{
+    // What one of this unit is worth in base units (m, s, kg, ...).
+    pub fn base_factor() -> number {
+        Self::terms()
+            .iter()
+            .map(|term| term.mult.powi(term.exponent))
+            .product()
+    }
+
+    pub fn base_value(&self) -> number {
+        self.inner * Self::base_factor()
+    }
+}
+
+// Implemented between `Derived`s that differ only in their units. The
+// temperature units can't have an offset, a product with °C has no meaningful
+// factor to K.
+pub trait SameDimension<Rhs> {
+    fn other_base_value(other: &Rhs) -> number;
+}
+
+// Two `Derived`s with the same exponents in every slot, possibly in different units.
+macro_rules! impl_same_dimension {
+    ($($body:tt)*) => {
+        impl<
+                LE: Number, L1: LengthUnit, L2: LengthUnit,
+                TE: Number, T1: TimeUnit, T2: TimeUnit,
+                TMPE: Number, TMP1: TempUnit, TMP2: TempUnit,
+                ME: Number, M1: MassUnit, M2: MassUnit,
+                CE: Number, C1: CurrentUnit, C2: CurrentUnit,
+                LIE: Number, LI1: LuminousIntensityUnit, LI2: LuminousIntensityUnit,
+                QE: Number, Q1: QuantityUnit, Q2: QuantityUnit,
+                AE: Number, A1: AngleUnit, A2: AngleUnit,
+                SE: Number, S1: SolidAngleUnit, S2: SolidAngleUnit,
+                DE: Number, D1: DigitalInformationUnit, D2: DigitalInformationUnit,
+                X1: SameExtra<X2>, X2: ExtraTrait,
+            >
+            $($body)*
+    };
+}
+
+type DerivedIn<LE, L, TE, T, TMPE, TMP, ME, M, CE, C, LIE, LI, QE, Q, AE, A, SE, S, DE, D, X> =
+    Derived<
+        LengthPower<LE, L>,
+        TimePower<TE, T>,
+        TempPower<TMPE, TMP>,
+        MassPower<ME, M>,
+        CurrentPower<CE, C>,
+        LuminousIntensityPower<LIE, LI>,
+        QuantityPower<QE, Q>,
+        AnglePower<AE, A>,
+        SolidAnglePower<SE, S>,
+        DigitalInformationPower<DE, D>,
+        X,
     >;
+
+impl<
+        L: LengthTrait,
+        T: TimeTrait,
+        TMP: LinearTemp,
+        M: MassTrait,
+        C: CurrentTrait,
+        LI: LuminousIntensityTrait,
+        Q: QuantityTrait,
+        A: AngleTrait,
+        S: SolidAngleTrait,
+        D: DigitalInformationTrait,
+        X: ExtraTrait,
+        Rhs,
+    > PartialEq<Rhs> for Derived<L, T, TMP, M, C, LI, Q, A, S, D, X>
+where
+    Self: SameDimension<Rhs>,
+{
+    fn eq(&self, other: &Rhs) -> bool {
+        self.base_value() == Self::other_base_value(other)
+    }
+}
+
+impl<
+        L: LengthTrait,
+        T: TimeTrait,
+        TMP: LinearTemp,
+        M: MassTrait,
+        C: CurrentTrait,
+        LI: LuminousIntensityTrait,
+        Q: QuantityTrait,
+        A: AngleTrait,
+        S: SolidAngleTrait,
+        D: DigitalInformationTrait,
+        X: ExtraTrait,
+        Rhs,
+    > PartialOrd<Rhs> for Derived<L, T, TMP, M, C, LI, Q, A, S, D, X>
+where
+    Self: SameDimension<Rhs>,
+{
+    fn partial_cmp(&self, other: &Rhs) -> Option<std::cmp::Ordering> {
+        self.base_value()
+            .partial_cmp(&Self::other_base_value(other))
+    }
+}
+
+impl_same_dimension! {
+    SameDimension<DerivedIn<LE, L2, TE, T2, TMPE, TMP2, ME, M2, CE, C2, LIE, LI2, QE, Q2, AE, A2, SE, S2, DE, D2, X2>>
+        for DerivedIn<LE, L1, TE, T1, TMPE, TMP1, ME, M1, CE, C1, LIE, LI1, QE, Q1, AE, A1, SE, S1, DE, D1, X1>
+    where
+        TMP1: LinearUnit,
+        TMP2: LinearUnit,
+    {
+        fn other_base_value(
+            other: &DerivedIn<LE, L2, TE, T2, TMPE, TMP2, ME, M2, CE, C2, LIE, LI2, QE, Q2, AE, A2, SE, S2, DE, D2, X2>,
+        ) -> number {
+            other.base_value()
+        }
+    }
 }
 
+// Between the same `Derived`s as `SameDimension`.
+pub trait ConvertFrom<Rhs> {
+    fn convert_from(other: Rhs) -> Self;
+}
+
+impl_same_dimension! {
+    ConvertFrom<DerivedIn<LE, L2, TE, T2, TMPE, TMP2, ME, M2, CE, C2, LIE, LI2, QE, Q2, AE, A2, SE, S2, DE, D2, X2>>
+        for DerivedIn<LE, L1, TE, T1, TMPE, TMP1, ME, M1, CE, C1, LIE, LI1, QE, Q1, AE, A1, SE, S1, DE, D1, X1>
+    where
+        TMP1: LinearUnit,
+        TMP2: LinearUnit,
+    {
+        fn convert_from(
+            other: DerivedIn<LE, L2, TE, T2, TMPE, TMP2, ME, M2, CE, C2, LIE, LI2, QE, Q2, AE, A2, SE, S2, DE, D2, X2>,
+        ) -> Self {
+            Self::new(other.base_value() / Self::base_factor())
+        }
+    }
+}
+
+// The dimension of a quantity as a `Derived`, exponents and units of every slot
+// in one type.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` is not a quantity",
+    note = "plain numbers scale a quantity with `*` and `/` instead"
+)]
+pub trait UnitToDerived {
+    type Dimension;
+    fn to_derived(self) -> Self::Dimension;
+}
+
+#[diagnostic::on_unimplemented(
+    message = "the result is not a single base quantity",
+    label = "this has more than one dimension, or one raised to a power other than 1",
+    note = "only a result like `Length<U>` or `Time<U>` converts back, `DimensionOp` keeps it as a `Product<A, B>` or `Quotient<A, B>`"
+)]
 pub trait DerivedToUnit {
     type Output;
     fn to_unit(self) -> Self::Output;
 }
 
-impl<T, U> MulHelper<U> for T
-where
-    T: UnitToDerived,
-    U: UnitToDerived,
+// `*` and `/` on dimensions are one type-level operation, `DimensionOp`, with
+// `Times` or `Per` deciding what happens to the exponents. Each slot is
+// combined on its own by `PowerOp`, where the units have to agree unless one
+// side is `None` and the exponent has to stay `InRange`.
+pub struct Times;
+pub struct Per;
 
-    T::LengthUnit: EqualsOrZero<U::LengthUnit>,
-    T::TimeUnit: EqualsOrZero<U::TimeUnit>,
-    T::TempUnit: EqualsOrZero<U::TempUnit>,
-    T::MassUnit: EqualsOrZero<U::MassUnit>,
-    T::CurrentUnit: EqualsOrZero<U::CurrentUnit>,
-    T::LuminousIntensityUnit: EqualsOrZero<U::LuminousIntensityUnit>,
-    T::QuantityUnit: EqualsOrZero<U::QuantityUnit>,
-    T::AngleUnit: EqualsOrZero<U::AngleUnit>,
-    T::SolidAngleUnit: EqualsOrZero<U::SolidAngleUnit>,
-    T::DigitalInformationUnit: EqualsOrZero<U::DigitalInformationUnit>,
-
-    <T::LengthUnit as EqualsOrZero<U::LengthUnit>>::SelfType: LengthUnit,
-    <T::TimeUnit as EqualsOrZero<U::TimeUnit>>::SelfType: TimeUnit,
-    <T::TempUnit as EqualsOrZero<U::TempUnit>>::SelfType: TempUnit,
-    <T::MassUnit as EqualsOrZero<U::MassUnit>>::SelfType: MassUnit,
-    <T::CurrentUnit as EqualsOrZero<U::CurrentUnit>>::SelfType: CurrentUnit,
-    <T::LuminousIntensityUnit as EqualsOrZero<U::LuminousIntensityUnit>>::SelfType:
-        LuminousIntensityUnit,
-    <T::QuantityUnit as EqualsOrZero<U::QuantityUnit>>::SelfType: QuantityUnit,
-    <T::AngleUnit as EqualsOrZero<U::AngleUnit>>::SelfType: AngleUnit,
-    <T::SolidAngleUnit as EqualsOrZero<U::SolidAngleUnit>>::SelfType: SolidAngleUnit,
-    <T::DigitalInformationUnit as EqualsOrZero<U::DigitalInformationUnit>>::SelfType:
-        DigitalInformationUnit,
-
-    T::LengthExp: Add<U::LengthExp>,
-    T::TimeExp: Add<U::TimeExp>,
-    T::TempExp: Add<U::TempExp>,
-    T::MassExp: Add<U::MassExp>,
-    T::CurrentExp: Add<U::CurrentExp>,
-    T::LuminousIntensityExp: Add<U::LuminousIntensityExp>,
-    T::QuantityExp: Add<U::QuantityExp>,
-    T::AngleExp: Add<U::AngleExp>,
-    T::SolidAngleExp: Add<U::SolidAngleExp>,
-    T::DigitalInformationExp: Add<U::DigitalInformationExp>,
-
-    // <T::LengthExp as Add<U::LengthExp>>::Output: Number,
-    // <T::TimeExp as Add<U::TimeExp>>::Output: Number,
-    <T::LengthExp as Add<U::LengthExp>>::Output: Number,
-    <T::TimeExp as Add<U::TimeExp>>::Output: Number,
-    <T::TempExp as Add<U::TempExp>>::Output: Number,
-    <T::MassExp as Add<U::MassExp>>::Output: Number,
-    <T::CurrentExp as Add<U::CurrentExp>>::Output: Number,
-    <T::LuminousIntensityExp as Add<U::LuminousIntensityExp>>::Output: Number,
-    <T::QuantityExp as Add<U::QuantityExp>>::Output: Number,
-    <T::AngleExp as Add<U::AngleExp>>::Output: Number,
-    <T::SolidAngleExp as Add<U::SolidAngleExp>>::Output: Number,
-    <T::DigitalInformationExp as Add<U::DigitalInformationExp>>::Output: Number,
-    Derived<
-        LengthPower<
-            <T::LengthExp as Add<U::LengthExp>>::Output,
-            <T::LengthUnit as EqualsOrZero<U::LengthUnit>>::SelfType,
-        >,
-        TimePower<
-            <T::TimeExp as Add<U::TimeExp>>::Output,
-            <T::TimeUnit as EqualsOrZero<U::TimeUnit>>::SelfType,
-        >,
-        TempPower<
-            <T::TempExp as Add<U::TempExp>>::Output,
-            <T::TempUnit as EqualsOrZero<U::TempUnit>>::SelfType,
-        >,
-        MassPower<
-            <T::MassExp as Add<U::MassExp>>::Output,
-            <T::MassUnit as EqualsOrZero<U::MassUnit>>::SelfType,
-        >,
-        CurrentPower<
-            <T::CurrentExp as Add<U::CurrentExp>>::Output,
-            <T::CurrentUnit as EqualsOrZero<U::CurrentUnit>>::SelfType,
-        >,
-        LuminousIntensityPower<
-            <T::LuminousIntensityExp as Add<U::LuminousIntensityExp>>::Output,
-            <T::LuminousIntensityUnit as EqualsOrZero<U::LuminousIntensityUnit>>::SelfType,
-        >,
-        QuantityPower<
-            <T::QuantityExp as Add<U::QuantityExp>>::Output,
-            <T::QuantityUnit as EqualsOrZero<U::QuantityUnit>>::SelfType,
-        >,
-        AnglePower<
-            <T::AngleExp as Add<U::AngleExp>>::Output,
-            <T::AngleUnit as EqualsOrZero<U::AngleUnit>>::SelfType,
-        >,
-        SolidAnglePower<
-            <T::SolidAngleExp as Add<U::SolidAngleExp>>::Output,
-            <T::SolidAngleUnit as EqualsOrZero<U::SolidAngleUnit>>::SelfType,
-        >,
-        DigitalInformationPower<
-            <T::DigitalInformationExp as Add<U::DigitalInformationExp>>::Output,
-            <T::DigitalInformationUnit as EqualsOrZero<U::DigitalInformationUnit>>::SelfType,
-        >,
-    >: DerivedToUnit,
+// How the values combine.
+pub trait Operation {
+    fn apply(lhs: number, rhs: number) -> number;
+}
+
+impl Operation for Times {
+    fn apply(lhs: number, rhs: number) -> number {
+        lhs * rhs
+    }
+}
+
+impl Operation for Per {
+    fn apply(lhs: number, rhs: number) -> number {
+        lhs / rhs
+    }
+}
+
+pub trait PowerOp<Rhs, Op> {
+    type Output;
+}
+
+pub trait DimensionOp<Rhs, Op> {
+    type Output;
+    fn apply(self, rhs: Rhs) -> Self::Output;
+}
+
+impl<
+        L1,
+        T1,
+        TMP1,
+        M1,
+        C1,
+        LI1,
+        Q1,
+        A1,
+        S1,
+        D1,
+        X1,
+        L2,
+        T2,
+        TMP2,
+        M2,
+        C2,
+        LI2,
+        Q2,
+        A2,
+        S2,
+        D2,
+        X2,
+        Op,
+    > DimensionOp<Derived<L2, T2, TMP2, M2, C2, LI2, Q2, A2, S2, D2, X2>, Op>
+    for Derived<L1, T1, TMP1, M1, C1, LI1, Q1, A1, S1, D1, X1>
+where
+    L1: PowerOp<L2, Op>,
+    T1: PowerOp<T2, Op>,
+    TMP1: PowerOp<TMP2, Op>,
+    M1: PowerOp<M2, Op>,
+    C1: PowerOp<C2, Op>,
+    LI1: PowerOp<LI2, Op>,
+    Q1: PowerOp<Q2, Op>,
+    A1: PowerOp<A2, Op>,
+    S1: PowerOp<S2, Op>,
+    D1: PowerOp<D2, Op>,
+    X1: PowerOp<X2, Op>,
+    Op: Operation,
 {
-    type Output = <Derived<
-        LengthPower<
-            <T::LengthExp as Add<U::LengthExp>>::Output,
-            <T::LengthUnit as EqualsOrZero<U::LengthUnit>>::SelfType,
-        >,
-        TimePower<
-            <T::TimeExp as Add<U::TimeExp>>::Output,
-            <T::TimeUnit as EqualsOrZero<U::TimeUnit>>::SelfType,
-        >,
-        TempPower<
-            <T::TempExp as Add<U::TempExp>>::Output,
-            <T::TempUnit as EqualsOrZero<U::TempUnit>>::SelfType,
-        >,
-        MassPower<
-            <T::MassExp as Add<U::MassExp>>::Output,
-            <T::MassUnit as EqualsOrZero<U::MassUnit>>::SelfType,
-        >,
-        CurrentPower<
-            <T::CurrentExp as Add<U::CurrentExp>>::Output,
-            <T::CurrentUnit as EqualsOrZero<U::CurrentUnit>>::SelfType,
-        >,
-        LuminousIntensityPower<
-            <T::LuminousIntensityExp as Add<U::LuminousIntensityExp>>::Output,
-            <T::LuminousIntensityUnit as EqualsOrZero<U::LuminousIntensityUnit>>::SelfType,
-        >,
-        QuantityPower<
-            <T::QuantityExp as Add<U::QuantityExp>>::Output,
-            <T::QuantityUnit as EqualsOrZero<U::QuantityUnit>>::SelfType,
-        >,
-        AnglePower<
-            <T::AngleExp as Add<U::AngleExp>>::Output,
-            <T::AngleUnit as EqualsOrZero<U::AngleUnit>>::SelfType,
-        >,
-        SolidAnglePower<
-            <T::SolidAngleExp as Add<U::SolidAngleExp>>::Output,
-            <T::SolidAngleUnit as EqualsOrZero<U::SolidAngleUnit>>::SelfType,
-        >,
-        DigitalInformationPower<
-            <T::DigitalInformationExp as Add<U::DigitalInformationExp>>::Output,
-            <T::DigitalInformationUnit as EqualsOrZero<U::DigitalInformationUnit>>::SelfType,
-        >,
-    > as DerivedToUnit>::Output;
+    #[allow(clippy::type_complexity)]
+    type Output = Derived<
+        L1::Output,
+        T1::Output,
+        TMP1::Output,
+        M1::Output,
+        C1::Output,
+        LI1::Output,
+        Q1::Output,
+        A1::Output,
+        S1::Output,
+        D1::Output,
+        X1::Output,
+    >;
 
-    fn multiply(self, rhs: U) -> Self::Output {
+    fn apply(self, rhs: Derived<L2, T2, TMP2, M2, C2, LI2, Q2, A2, S2, D2, X2>) -> Self::Output {
         Derived {
+            inner: Op::apply(self.inner, rhs.inner),
             types: PhantomData,
-            inner: self.to_derived().inner * rhs.to_derived().inner,
         }
-        .to_unit()
     }
 }
 
-impl<T, U> DivHelper<U> for T
+// One unit of a compound `q!` literal. Units with an offset (°C) have no
+// factor to the base unit inside a `Derived`, so they're rejected here.
+#[doc(hidden)]
+pub fn compound_term<T>(quantity: T) -> T::Dimension
+where
+    T: UnitToDerived,
+    T::Dimension: SameDimension<T::Dimension>,
+{
+    quantity.to_derived()
+}
+
+// The dimension of `A * B` and `A / B` as a `Derived`, for results that aren't a
+// single quantity, e.g. `Quotient<Length<Meter>, Time<Second>>`.
+pub type Product<A, B> = <<A as UnitToDerived>::Dimension as DimensionOp<
+    <B as UnitToDerived>::Dimension,
+    Times,
+>>::Output;
+pub type Quotient<A, B> =
+    <<A as UnitToDerived>::Dimension as DimensionOp<<B as UnitToDerived>::Dimension, Per>>::Output;
+
+impl<T, U, P> MulHelper<U> for T
 where
     T: UnitToDerived,
     U: UnitToDerived,
+    T::Dimension: DimensionOp<U::Dimension, Times, Output = P>,
+    P: DerivedToUnit,
+{
+    type Output = P::Output;
 
-    T::LengthUnit: EqualsOrZero<U::LengthUnit>,
-    T::TimeUnit: EqualsOrZero<U::TimeUnit>,
-    T::TempUnit: EqualsOrZero<U::TempUnit>,
-    T::MassUnit: EqualsOrZero<U::MassUnit>,
-    T::CurrentUnit: EqualsOrZero<U::CurrentUnit>,
-    T::LuminousIntensityUnit: EqualsOrZero<U::LuminousIntensityUnit>,
-    T::QuantityUnit: EqualsOrZero<U::QuantityUnit>,
-    T::AngleUnit: EqualsOrZero<U::AngleUnit>,
-    T::SolidAngleUnit: EqualsOrZero<U::SolidAngleUnit>,
-    T::DigitalInformationUnit: EqualsOrZero<U::DigitalInformationUnit>,
-
-    <T::LengthUnit as EqualsOrZero<U::LengthUnit>>::SelfType: LengthUnit,
-    <T::TimeUnit as EqualsOrZero<U::TimeUnit>>::SelfType: TimeUnit,
-    <T::TempUnit as EqualsOrZero<U::TempUnit>>::SelfType: TempUnit,
-    <T::MassUnit as EqualsOrZero<U::MassUnit>>::SelfType: MassUnit,
-    <T::CurrentUnit as EqualsOrZero<U::CurrentUnit>>::SelfType: CurrentUnit,
-    <T::LuminousIntensityUnit as EqualsOrZero<U::LuminousIntensityUnit>>::SelfType:
-        LuminousIntensityUnit,
-    <T::QuantityUnit as EqualsOrZero<U::QuantityUnit>>::SelfType: QuantityUnit,
-    <T::AngleUnit as EqualsOrZero<U::AngleUnit>>::SelfType: AngleUnit,
-    <T::SolidAngleUnit as EqualsOrZero<U::SolidAngleUnit>>::SelfType: SolidAngleUnit,
-    <T::DigitalInformationUnit as EqualsOrZero<U::DigitalInformationUnit>>::SelfType:
-        DigitalInformationUnit,
-
-    T::LengthExp: Add<<U::LengthExp as Number>::Neg>,
-    T::TimeExp: Add<<U::TimeExp as Number>::Neg>,
-    T::TempExp: Add<<U::TempExp as Number>::Neg>,
-    T::MassExp: Add<<U::MassExp as Number>::Neg>,
-    T::CurrentExp: Add<<U::CurrentExp as Number>::Neg>,
-    T::LuminousIntensityExp: Add<<U::LuminousIntensityExp as Number>::Neg>,
-    T::QuantityExp: Add<<U::QuantityExp as Number>::Neg>,
-    T::AngleExp: Add<<U::AngleExp as Number>::Neg>,
-    T::SolidAngleExp: Add<<U::SolidAngleExp as Number>::Neg>,
-    T::DigitalInformationExp: Add<<U::DigitalInformationExp as Number>::Neg>,
-
-    <T::LengthExp as Add<<U::LengthExp as Number>::Neg>>::Output: Number,
-    <T::TimeExp as Add<<U::TimeExp as Number>::Neg>>::Output: Number,
-    <T::TempExp as Add<<U::TempExp as Number>::Neg>>::Output: Number,
-    <T::MassExp as Add<<U::MassExp as Number>::Neg>>::Output: Number,
-    <T::CurrentExp as Add<<U::CurrentExp as Number>::Neg>>::Output: Number,
-    <T::LuminousIntensityExp as Add<<U::LuminousIntensityExp as Number>::Neg>>::Output: Number,
-    <T::QuantityExp as Add<<U::QuantityExp as Number>::Neg>>::Output: Number,
-    <T::AngleExp as Add<<U::AngleExp as Number>::Neg>>::Output: Number,
-    <T::SolidAngleExp as Add<<U::SolidAngleExp as Number>::Neg>>::Output: Number,
-    <T::DigitalInformationExp as Add<<U::DigitalInformationExp as Number>::Neg>>::Output: Number,
+    fn multiply(self, rhs: U) -> Self::Output {
+        self.to_derived().apply(rhs.to_derived()).to_unit()
+    }
+}
 
-    Derived<
-        // LengthPower<
-        //     <T::LengthExp as Add<<U::LengthExp as Number>::Neg>>::Output,
-        //     <T::LengthUnit as EqualsOrZero<U::LengthUnit>>::SelfType,
-        // >,
-        // TimePower<
-        //     <T::TimeExp as Add<<U::TimeExp as Number>::Neg>>::Output,
-        //     <T::TimeUnit as EqualsOrZero<U::TimeUnit>>::SelfType,
-        // >,
-        LengthPower<
-            <T::LengthExp as Add<<U::LengthExp as Number>::Neg>>::Output,
-            <T::LengthUnit as EqualsOrZero<U::LengthUnit>>::SelfType,
-        >,
-        TimePower<
-            <T::TimeExp as Add<<U::TimeExp as Number>::Neg>>::Output,
-            <T::TimeUnit as EqualsOrZero<U::TimeUnit>>::SelfType,
-        >,
-        TempPower<
-            <T::TempExp as Add<<U::TempExp as Number>::Neg>>::Output,
-            <T::TempUnit as EqualsOrZero<U::TempUnit>>::SelfType,
-        >,
-        MassPower<
-            <T::MassExp as Add<<U::MassExp as Number>::Neg>>::Output,
-            <T::MassUnit as EqualsOrZero<U::MassUnit>>::SelfType,
-        >,
-        CurrentPower<
-            <T::CurrentExp as Add<<U::CurrentExp as Number>::Neg>>::Output,
-            <T::CurrentUnit as EqualsOrZero<U::CurrentUnit>>::SelfType,
-        >,
-        LuminousIntensityPower<
-            <T::LuminousIntensityExp as Add<<U::LuminousIntensityExp as Number>::Neg>>::Output,
-            <T::LuminousIntensityUnit as EqualsOrZero<U::LuminousIntensityUnit>>::SelfType,
-        >,
-        QuantityPower<
-            <T::QuantityExp as Add<<U::QuantityExp as Number>::Neg>>::Output,
-            <T::QuantityUnit as EqualsOrZero<U::QuantityUnit>>::SelfType,
-        >,
-        AnglePower<
-            <T::AngleExp as Add<<U::AngleExp as Number>::Neg>>::Output,
-            <T::AngleUnit as EqualsOrZero<U::AngleUnit>>::SelfType,
-        >,
-        SolidAnglePower<
-            <T::SolidAngleExp as Add<<U::SolidAngleExp as Number>::Neg>>::Output,
-            <T::SolidAngleUnit as EqualsOrZero<U::SolidAngleUnit>>::SelfType,
-        >,
-        DigitalInformationPower<
-            <T::DigitalInformationExp as Add<<U::DigitalInformationExp as Number>::Neg>>::Output,
-            <T::DigitalInformationUnit as EqualsOrZero<U::DigitalInformationUnit>>::SelfType,
-        >,
-    >: DerivedToUnit,
+impl<T, U, P> DivHelper<U> for T
+where
+    T: UnitToDerived,
+    U: UnitToDerived,
+    T::Dimension: DimensionOp<U::Dimension, Per, Output = P>,
+    P: DerivedToUnit,
 {
-    type Output = <Derived<
-        LengthPower<
-            <T::LengthExp as Add<<U::LengthExp as Number>::Neg>>::Output,
-            <T::LengthUnit as EqualsOrZero<U::LengthUnit>>::SelfType,
-        >,
-        TimePower<
-            <T::TimeExp as Add<<U::TimeExp as Number>::Neg>>::Output,
-            <T::TimeUnit as EqualsOrZero<U::TimeUnit>>::SelfType,
-        >,
-        TempPower<
-            <T::TempExp as Add<<U::TempExp as Number>::Neg>>::Output,
-            <T::TempUnit as EqualsOrZero<U::TempUnit>>::SelfType,
-        >,
-        MassPower<
-            <T::MassExp as Add<<U::MassExp as Number>::Neg>>::Output,
-            <T::MassUnit as EqualsOrZero<U::MassUnit>>::SelfType,
-        >,
-        CurrentPower<
-            <T::CurrentExp as Add<<U::CurrentExp as Number>::Neg>>::Output,
-            <T::CurrentUnit as EqualsOrZero<U::CurrentUnit>>::SelfType,
-        >,
-        LuminousIntensityPower<
-            <T::LuminousIntensityExp as Add<<U::LuminousIntensityExp as Number>::Neg>>::Output,
-            <T::LuminousIntensityUnit as EqualsOrZero<U::LuminousIntensityUnit>>::SelfType,
-        >,
-        QuantityPower<
-            <T::QuantityExp as Add<<U::QuantityExp as Number>::Neg>>::Output,
-            <T::QuantityUnit as EqualsOrZero<U::QuantityUnit>>::SelfType,
-        >,
-        AnglePower<
-            <T::AngleExp as Add<<U::AngleExp as Number>::Neg>>::Output,
-            <T::AngleUnit as EqualsOrZero<U::AngleUnit>>::SelfType,
-        >,
-        SolidAnglePower<
-            <T::SolidAngleExp as Add<<U::SolidAngleExp as Number>::Neg>>::Output,
-            <T::SolidAngleUnit as EqualsOrZero<U::SolidAngleUnit>>::SelfType,
-        >,
-        DigitalInformationPower<
-            <T::DigitalInformationExp as Add<<U::DigitalInformationExp as Number>::Neg>>::Output,
-            <T::DigitalInformationUnit as EqualsOrZero<U::DigitalInformationUnit>>::SelfType,
-        >,
-    > as DerivedToUnit>::Output;
+    type Output = P::Output;
 
     fn divide(self, rhs: U) -> Self::Output {
-        Derived {
-            types: PhantomData,
-            inner: self.to_derived().inner / rhs.to_derived().inner,
-        }
-        .to_unit()
+        self.to_derived().apply(rhs.to_derived()).to_unit()
     }
 }
@@ -1,44 +1,83 @@
+use crate::math::number;
+
+// `+` and `-` take any right-hand side implementing these, which only the
+// quantity itself does. Mixing quantities then fails here, naming both sides,
+// instead of as a plain type mismatch.
+#[diagnostic::on_unimplemented(
+    message = "cannot add `{Self}` to `{Lhs}`",
+    label = "expected `{Lhs}`",
+    note = "only the same dimension in the same unit adds up, `.convert::<U>()` changes the unit"
+)]
+pub trait AddTo<Lhs> {
+    fn value(self) -> number;
+}
+
+#[diagnostic::on_unimplemented(
+    message = "cannot subtract `{Self}` from `{Lhs}`",
+    label = "expected `{Lhs}`",
+    note = "only the same dimension in the same unit subtracts, `.convert::<U>()` changes the unit"
+)]
+pub trait SubFrom<Lhs> {
+    fn value(self) -> number;
+}
+
 macro_rules! impl_math {
     ($struct_name:ident<$($generic:ident : $trait:path),+>) => {
 
-        impl<$($generic),+> std::ops::Add for $struct_name <  $($generic),+ >
+        impl<$($generic),+> $crate::math_impl::AddTo<Self> for $struct_name <  $($generic),+ >
+        where $($generic : $trait),+
+        {
+            fn value(self) -> number {
+                self.inner
+            }
+        }
+
+        impl<$($generic),+> $crate::math_impl::SubFrom<Self> for $struct_name <  $($generic),+ >
         where $($generic : $trait),+
+        {
+            fn value(self) -> number {
+                self.inner
+            }
+        }
+
+        impl<Rhs, $($generic),+> std::ops::Add<Rhs> for $struct_name <  $($generic),+ >
+        where $($generic : $trait),+, Rhs: $crate::math_impl::AddTo<Self>
         {
             type Output = Self;
 
-            fn add(self, rhs: Self) -> Self::Output {
+            fn add(self, rhs: Rhs) -> Self::Output {
                 $struct_name {
-                    inner: self.inner + rhs.inner,
+                    inner: self.inner + rhs.value(),
                     ..self
                 }
             }
         }
 
-        impl<$($generic),+> std::ops::AddAssign for $struct_name <  $($generic),+ >
-        where $($generic : $trait),+
+        impl<Rhs, $($generic),+> std::ops::AddAssign<Rhs> for $struct_name <  $($generic),+ >
+        where $($generic : $trait),+, Rhs: $crate::math_impl::AddTo<Self>
         {
-            fn add_assign(&mut self, rhs: Self){
+            fn add_assign(&mut self, rhs: Rhs){
                 *self = *self + rhs;
             }
         }
 
-        impl<$($generic),+> std::ops::Sub for $struct_name <  $($generic),+ >
-        where $($generic : $trait),+
+        impl<Rhs, $($generic),+> std::ops::Sub<Rhs> for $struct_name <  $($generic),+ >
+        where $($generic : $trait),+, Rhs: $crate::math_impl::SubFrom<Self>
         {
             type Output = Self;
 
-            fn sub(self, rhs: Self) -> Self::Output {
+            fn sub(self, rhs: Rhs) -> Self::Output {
                 $struct_name {
-                    inner: self.inner - rhs.inner,
+                    inner: self.inner - rhs.value(),
                     ..self
                 }
             }
         }
 
-        impl<$($generic),+> std::ops::SubAssign for $struct_name <  $($generic),+ >
-        where $($generic : $trait),+
+        impl<Rhs, $($generic),+> std::ops::SubAssign<Rhs> for $struct_name <  $($generic),+ >
+        where $($generic : $trait),+, Rhs: $crate::math_impl::SubFrom<Self>
         {
-            fn sub_assign(&mut self, rhs: Self) {
+            fn sub_assign(&mut self, rhs: Rhs) {
                 *self = *self - rhs;
             }
         }
@@ -106,13 +145,144 @@ macro_rules! impl_math {
             }
         }
 
+        // Only between the same unit, the values are divided as they are.
         impl<$($generic),+> std::ops::Div for $struct_name <  $($generic),+ >
         where $($generic : $trait),+
         {
-            type Output = number;
+            type Output = $crate::derived::ratio::Ratio<$crate::derived::ratio::Fraction>;
 
             fn div(self, rhs: Self) -> Self::Output {
-                self.inner / rhs.inner
+                $crate::derived::ratio::Ratio::new(self.inner / rhs.inner)
+            }
+        }
+
+        impl<$($generic),+> std::ops::Rem for $struct_name <  $($generic),+ >
+        where $($generic : $trait),+
+        {
+            type Output = Self;
+
+            fn rem(self, rhs: Self) -> Self::Output {
+                $struct_name {
+                    inner: self.inner % rhs.inner,
+                    ..self
+                }
+            }
+        }
+
+        impl<$($generic),+> std::ops::RemAssign for $struct_name <  $($generic),+ >
+        where $($generic : $trait),+
+        {
+            fn rem_assign(&mut self, rhs: Self) {
+                *self = *self % rhs;
+            }
+        }
+
+        impl<$($generic),+> std::ops::Neg for $struct_name <  $($generic),+ >
+        where $($generic : $trait),+
+        {
+            type Output = Self;
+
+            fn neg(self) -> Self::Output {
+                $struct_name {
+                    inner: -self.inner,
+                    ..self
+                }
+            }
+        }
+
+        impl<$($generic),+> std::ops::Mul<$struct_name <  $($generic),+ >> for number
+        where $($generic : $trait),+
+        {
+            type Output = $struct_name <  $($generic),+ >;
+
+            fn mul(self, rhs: $struct_name <  $($generic),+ >) -> Self::Output {
+                rhs * self
+            }
+        }
+
+        impl<$($generic),+> std::iter::Sum for $struct_name <  $($generic),+ >
+        where $($generic : $trait),+
+        {
+            fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+                $struct_name {
+                    inner: iter.map(|x| x.inner).sum(),
+                    types: std::marker::PhantomData,
+                }
+            }
+        }
+
+        impl<'a, $($generic),+> std::iter::Sum<&'a Self> for $struct_name <  $($generic),+ >
+        where $($generic : $trait),+
+        {
+            fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+                iter.copied().sum()
+            }
+        }
+
+        impl<$($generic),+> $struct_name <  $($generic),+ >
+        where $($generic : $trait),+
+        {
+            pub fn abs(self) -> Self {
+                $struct_name {
+                    inner: self.inner.abs(),
+                    ..self
+                }
+            }
+
+            pub fn min(self, other: Self) -> Self {
+                $struct_name {
+                    inner: self.inner.min(other.inner),
+                    ..self
+                }
+            }
+
+            pub fn max(self, other: Self) -> Self {
+                $struct_name {
+                    inner: self.inner.max(other.inner),
+                    ..self
+                }
+            }
+
+            pub fn clamp(self, min: Self, max: Self) -> Self {
+                $struct_name {
+                    inner: self.inner.clamp(min.inner, max.inner),
+                    ..self
+                }
+            }
+
+            pub fn signum(self) -> number {
+                self.inner.signum()
+            }
+
+            // Rounding happens in the current unit, see `round_in` and friends
+            // for rounding to whole amounts of another unit.
+            pub fn floor(self) -> Self {
+                $struct_name {
+                    inner: self.inner.floor(),
+                    ..self
+                }
+            }
+
+            pub fn ceil(self) -> Self {
+                $struct_name {
+                    inner: self.inner.ceil(),
+                    ..self
+                }
+            }
+
+            pub fn round(self) -> Self {
+                $struct_name {
+                    inner: self.inner.round(),
+                    ..self
+                }
+            }
+
+            pub fn is_finite(self) -> bool {
+                self.inner.is_finite()
+            }
+
+            pub fn is_nan(self) -> bool {
+                self.inner.is_nan()
             }
         }
 
@@ -141,4 +311,69 @@ macro_rules! impl_math {
 
 }
 
+macro_rules! impl_cmp {
+    ($struct_name:ident<$generic:ident : $trait:path>) => {
+        impl<$generic: $trait, Rhs: $trait> PartialEq<$struct_name<Rhs>>
+            for $struct_name<$generic>
+        {
+            fn eq(&self, other: &$struct_name<Rhs>) -> bool {
+                <$generic as $trait>::to_base(*self).inner == <Rhs as $trait>::to_base(*other).inner
+            }
+        }
+
+        impl<$generic: $trait, Rhs: $trait> PartialOrd<$struct_name<Rhs>>
+            for $struct_name<$generic>
+        {
+            fn partial_cmp(&self, other: &$struct_name<Rhs>) -> Option<std::cmp::Ordering> {
+                <$generic as $trait>::to_base(*self)
+                    .inner
+                    .partial_cmp(&<Rhs as $trait>::to_base(*other).inner)
+            }
+        }
+
+        impl<$generic: $trait> $struct_name<$generic> {
+            // `abs` is in the units of `self`.
+            pub fn approx_eq<Rhs: $trait>(
+                &self,
+                other: &$struct_name<Rhs>,
+                rel: number,
+                abs: number,
+            ) -> bool {
+                $crate::math_impl::approx_eq(
+                    self.inner,
+                    other.convert::<$generic>().inner,
+                    rel,
+                    abs,
+                )
+            }
+        }
+    };
+}
+
+macro_rules! impl_rounding {
+    ($struct_name:ident<$generic:ident : $trait:path>) => {
+        impl<$generic: $trait> $struct_name<$generic> {
+            // Rounds to a whole number of `U`s, e.g. `round_in::<Minute>()`,
+            // keeping the unit of `self`.
+            pub fn floor_in<U: $trait>(self) -> Self {
+                self.convert::<U>().floor().convert()
+            }
+
+            pub fn ceil_in<U: $trait>(self) -> Self {
+                self.convert::<U>().ceil().convert()
+            }
+
+            pub fn round_in<U: $trait>(self) -> Self {
+                self.convert::<U>().round().convert()
+            }
+        }
+    };
+}
+
+pub(crate) fn approx_eq(a: number, b: number, rel: number, abs: number) -> bool {
+    a == b || (a - b).abs() <= abs.max(rel * a.abs().max(b.abs()))
+}
+
+pub(crate) use impl_cmp;
 pub(crate) use impl_math;
+pub(crate) use impl_rounding;
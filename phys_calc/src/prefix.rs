@@ -0,0 +1,278 @@
+use std::any::TypeId;
+use std::collections::HashMap;
+use std::marker::PhantomData;
+use std::sync::{OnceLock, PoisonError, RwLock};
+
+use crate::base::unit_traits::*;
+use crate::derived::unit_traits::*;
+use crate::extra::ExtraUnit;
+use crate::math::{number, EqualsOrZero};
+use crate::unit::{LinearUnit, None, Prefixable, Symbols};
+use crate::Unit;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Prefix {
+    pub symbol: &'static str,
+    pub name: &'static str,
+    pub base: u32,
+    pub exponent: i32,
+}
+
+impl Prefix {
+    const fn new(symbol: &'static str, name: &'static str, base: u32, exponent: i32) -> Self {
+        Prefix {
+            symbol,
+            name,
+            base,
+            exponent,
+        }
+    }
+
+    pub fn factor(&self) -> number {
+        (self.base as number).powi(self.exponent)
+    }
+
+    pub fn ascii(&self) -> &'static str {
+        match self.symbol {
+            "μ" => "u",
+            symbol => symbol,
+        }
+    }
+
+    pub fn latex(&self) -> String {
+        match self.name {
+            "" => String::new(),
+            name => format!("\\{name}"),
+        }
+    }
+
+    pub fn si(exponent: i32) -> Option<Prefix> {
+        SI_PREFIXES.iter().copied().find(|p| p.exponent == exponent)
+    }
+
+    pub fn binary(exponent: i32) -> Option<Prefix> {
+        BINARY_PREFIXES
+            .iter()
+            .copied()
+            .find(|p| p.exponent == exponent)
+    }
+}
+
+pub const NO_PREFIX: Prefix = Prefix::new("", "", 10, 0);
+
+pub const SI_PREFIXES: [Prefix; 25] = [
+    Prefix::new("q", "quecto", 10, -30),
+    Prefix::new("r", "ronto", 10, -27),
+    Prefix::new("y", "yocto", 10, -24),
+    Prefix::new("z", "zepto", 10, -21),
+    Prefix::new("a", "atto", 10, -18),
+    Prefix::new("f", "femto", 10, -15),
+    Prefix::new("p", "pico", 10, -12),
+    Prefix::new("n", "nano", 10, -9),
+    Prefix::new("μ", "micro", 10, -6),
+    Prefix::new("m", "milli", 10, -3),
+    Prefix::new("c", "centi", 10, -2),
+    Prefix::new("d", "deci", 10, -1),
+    NO_PREFIX,
+    Prefix::new("da", "deca", 10, 1),
+    Prefix::new("h", "hecto", 10, 2),
+    Prefix::new("k", "kilo", 10, 3),
+    Prefix::new("M", "mega", 10, 6),
+    Prefix::new("G", "giga", 10, 9),
+    Prefix::new("T", "tera", 10, 12),
+    Prefix::new("P", "peta", 10, 15),
+    Prefix::new("E", "exa", 10, 18),
+    Prefix::new("Z", "zetta", 10, 21),
+    Prefix::new("Y", "yotta", 10, 24),
+    Prefix::new("R", "ronna", 10, 27),
+    Prefix::new("Q", "quetta", 10, 30),
+];
+
+pub const BINARY_PREFIXES: [Prefix; 9] = [
+    Prefix::new("", "", 1024, 0),
+    Prefix::new("Ki", "kibi", 1024, 1),
+    Prefix::new("Mi", "mebi", 1024, 2),
+    Prefix::new("Gi", "gibi", 1024, 3),
+    Prefix::new("Ti", "tebi", 1024, 4),
+    Prefix::new("Pi", "pebi", 1024, 5),
+    Prefix::new("Ei", "exbi", 1024, 6),
+    Prefix::new("Zi", "zebi", 1024, 7),
+    Prefix::new("Yi", "yobi", 1024, 8),
+];
+
+// A prefix from one of the tables by exponent, usable in constants.
+const fn find_prefix(prefixes: &[Prefix], exponent: i32) -> Prefix {
+    let mut i = 0;
+    while i < prefixes.len() {
+        if prefixes[i].exponent == exponent {
+            return prefixes[i];
+        }
+        i += 1;
+    }
+    panic!("no prefix with this exponent")
+}
+
+// Symbols are `&'static str`, so the ones of a prefixed unit are built the
+// first time they're asked for and kept for the rest of the program, once per
+// unit and unit trait. Later lookups only take the read lock.
+type SymbolCache = RwLock<HashMap<(TypeId, &'static str), Symbols>>;
+
+fn cached(key: (TypeId, &'static str), build: impl FnOnce() -> Symbols) -> Symbols {
+    static CACHE: OnceLock<SymbolCache> = OnceLock::new();
+    let cache = CACHE.get_or_init(Default::default);
+    let read = cache.read().unwrap_or_else(PoisonError::into_inner);
+    if let Some(symbols) = read.get(&key) {
+        return *symbols;
+    }
+    drop(read);
+    *cache
+        .write()
+        .unwrap_or_else(PoisonError::into_inner)
+        .entry(key)
+        .or_insert_with(build)
+}
+
+fn prefixed(prefix: Prefix, symbols: Symbols) -> Symbols {
+    let leak = |symbol: String| -> &'static str { Box::leak(symbol.into_boxed_str()) };
+    Symbols {
+        unicode: leak(format!("{}{}", prefix.symbol, symbols.unicode)),
+        ascii: leak(format!("{}{}", prefix.ascii(), symbols.ascii)),
+        latex: leak(format!("{}{}", prefix.latex(), symbols.latex)),
+        singular: leak(format!("{}{}", prefix.name, symbols.singular)),
+        plural: leak(format!("{}{}", prefix.name, symbols.plural)),
+    }
+}
+
+pub trait UnitPrefix {
+    const PREFIX: Prefix;
+}
+
+// `Kilo<Meter>`, `Micro<Ampere>`, `Kibi<Byte>`, ...: a unit with a prefix in
+// front, implementing each of the listed unit traits the inner unit does.
+// Units with an offset (°C, °F) can't be prefixed, nor ones that aren't
+// `Prefixable` (`Kilo<Kilometer>`, `Kilo<SquareMeter>`).
+macro_rules! impl_prefixed_unit {
+    ($prefix:ident, [$($trait:ident),+]) => {
+        $(impl_prefixed_unit!(@one $prefix, $trait);)+
+    };
+    (@one $prefix:ident, ExtraUnit) => {
+        impl<U: ExtraUnit + LinearUnit + Prefixable + 'static> ExtraUnit for $prefix<U> {
+            type Dimension = U::Dimension;
+
+            fn mult() -> number {
+                Self::PREFIX.factor() * <U as ExtraUnit>::mult()
+            }
+
+            fn unit() -> &'static str {
+                <Self as ExtraUnit>::symbols().unicode
+            }
+
+            fn symbols() -> Symbols {
+                cached((TypeId::of::<Self>(), "ExtraUnit"), || {
+                    prefixed(Self::PREFIX, <U as ExtraUnit>::symbols())
+                })
+            }
+        }
+    };
+    (@one $prefix:ident, $trait:ident) => {
+        impl<U: $trait + LinearUnit + Prefixable + 'static> $trait for $prefix<U> {
+            fn unit() -> &'static str {
+                <Self as $trait>::symbols().unicode
+            }
+
+            fn symbols() -> Symbols {
+                cached((TypeId::of::<Self>(), stringify!($trait)), || {
+                    prefixed(Self::PREFIX, <U as $trait>::symbols())
+                })
+            }
+
+            fn mult() -> number {
+                Self::PREFIX.factor() * <U as $trait>::mult()
+            }
+        }
+    };
+}
+
+macro_rules! prefixes {
+    ($table:ident, $traits:tt, $($prefix:ident: $exponent:literal),+ $(,)?) => {
+        $(
+            #[derive(Copy, Clone, Debug)]
+            pub struct $prefix<U>(PhantomData<U>);
+
+            impl<U> UnitPrefix for $prefix<U> {
+                const PREFIX: Prefix = find_prefix(&$table, $exponent);
+            }
+
+            impl<U: Unit> Unit for $prefix<U> {}
+            impl<U: LinearUnit> LinearUnit for $prefix<U> {}
+            impl<U> EqualsOrZero for $prefix<U> {
+                type SelfType = Self;
+            }
+            impl<U> EqualsOrZero<None> for $prefix<U> {
+                type SelfType = Self;
+            }
+
+            impl_prefixed_unit!($prefix, $traits);
+        )+
+    };
+}
+
+prefixes!(
+    SI_PREFIXES,
+    [
+        LengthUnit,
+        TimeUnit,
+        TempUnit,
+        MassUnit,
+        CurrentUnit,
+        LuminousIntensityUnit,
+        QuantityUnit,
+        AngleUnit,
+        SolidAngleUnit,
+        DigitalInformationUnit,
+        VolumeUnit,
+        VelocityUnit,
+        DataRateUnit,
+        FrequencyUnit,
+        AngularVelocityUnit,
+        ExtraUnit
+    ],
+    Quecto: -30,
+    Ronto: -27,
+    Yocto: -24,
+    Zepto: -21,
+    Atto: -18,
+    Femto: -15,
+    Pico: -12,
+    Nano: -9,
+    Micro: -6,
+    Milli: -3,
+    Centi: -2,
+    Deci: -1,
+    Deca: 1,
+    Hecto: 2,
+    Kilo: 3,
+    Mega: 6,
+    Giga: 9,
+    Tera: 12,
+    Peta: 15,
+    Exa: 18,
+    Zetta: 21,
+    Yotta: 24,
+    Ronna: 27,
+    Quetta: 30,
+);
+
+// IEC prefixes are only defined for information and its rates.
+prefixes!(
+    BINARY_PREFIXES,
+    [DigitalInformationUnit, DataRateUnit],
+    Kibi: 1,
+    Mebi: 2,
+    Gibi: 3,
+    Tebi: 4,
+    Pebi: 5,
+    Exbi: 6,
+    Zebi: 7,
+    Yobi: 8,
+);
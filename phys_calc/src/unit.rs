@@ -6,11 +6,42 @@ pub trait Unit: Copy + Clone + Sized {
     // fn unit() -> &'static str;
 }
 
+// Every way a unit can be written; see `format::SymbolStyle`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Symbols {
+    pub unicode: &'static str,
+    pub ascii: &'static str,
+    // Contents of a siunitx `\si{...}`, e.g. `\micro\metre`. Units siunitx has
+    // no macro for are wrapped in `\text{...}`, e.g. `\text{ft}`.
+    pub latex: &'static str,
+    pub singular: &'static str,
+    pub plural: &'static str,
+}
+
+// Units that are a plain multiple of the base unit, everything `#[derive(Unit)]`
+// produces. Celsius and Fahrenheit have an offset, so they don't take part in
+// `Derived` conversions.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` has an offset from the base unit and can't be converted or compared as part of a `Derived`",
+    note = "convert the `Temperature` on its own, or use `Kelvin`"
+)]
+pub trait LinearUnit: Unit {}
+
+// Units an SI or binary prefix can go in front of. A prefix scales the whole
+// unit, so `#[derive(Unit)]` leaves out units that already have one
+// (kilometer) and powers of a unit (m², where kilo would make km² 1000 m²).
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` can't take a prefix",
+    note = "it already has a prefix, or is a power of a unit the prefix would be raised with"
+)]
+pub trait Prefixable: Unit {}
+
 #[derive(Copy, Clone, Debug)]
 pub struct None;
 impl<T> EqualsOrZero<T> for None {
     type SelfType = T;
 }
+impl LinearUnit for None {}
 
 macro_rules! impl_none {
     ($ident:ident) => {
@@ -22,6 +53,10 @@ macro_rules! impl_none {
             fn unit() -> &'static str {
                 panic!("`None` is not a regular unit!")
             }
+
+            fn symbols() -> Symbols {
+                panic!("`None` is not a regular unit!")
+            }
         }
     };
 }
@@ -54,6 +89,10 @@ impl TempUnit for None {
         panic!("`None` is not a regular unit!")
     }
 
+    fn symbols() -> Symbols {
+        panic!("`None` is not a regular unit!")
+    }
+
     fn mult() -> number {
         panic!("`None` is not a regular unit!")
     }
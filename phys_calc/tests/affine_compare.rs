@@ -0,0 +1,11 @@
+use phys_calc::literals::*;
+
+// Quantities compare through `to_base`, which knows about the offsets.
+#[test]
+fn affine_compare() {
+    let boiling = Temperature::<Fahrenheit>::new(212.);
+    assert_eq!(boiling, Temperature::<Celsius>::new(100.));
+    assert!(boiling.approx_eq(&Temperature::<Kelvin>::new(373.15), 1e-12, 0.));
+    assert!(boiling > Temperature::<Kelvin>::new(300.));
+    assert!(Temperature::<Fahrenheit>::new(0.) < Temperature::<Celsius>::new(0.));
+}
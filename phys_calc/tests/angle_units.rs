@@ -0,0 +1,45 @@
+use phys_calc::format::SymbolStyle;
+use phys_calc::literals::*;
+use phys_calc::q;
+
+#[test]
+fn angle_units() {
+    assert_eq!(1.tr().convert::<Degree>(), 360.deg());
+    assert_eq!(Angle::<Revolution>::new(0.5), 180.deg());
+    assert!(1600.mil().approx_eq(&90.deg(), 1e-12, 0.));
+    assert!(8.pt().approx_eq(&90.deg(), 1e-12, 0.));
+    assert!(q!(6 HA).approx_eq(&90.deg(), 1e-12, 0.));
+    assert!(1.HAs().approx_eq(&15.arcsec(), 1e-12, 0.));
+    assert!(q!(1 tr).approx_eq(&6400.mil(), 1e-12, 0.));
+}
+
+#[test]
+fn hour_angles() {
+    let betelgeuse = Angle::<Degree>::parse_hour_angle("5h 55m 10.3s").unwrap();
+    assert!(betelgeuse.approx_eq(&88.792_916_666_666_67.deg(), 1e-9, 0.));
+    assert_eq!(
+        betelgeuse.hour_angle().decimals(1).to_string(),
+        "5ʰ55ᵐ10.3ˢ"
+    );
+    assert_eq!(
+        betelgeuse
+            .hour_angle()
+            .decimals(1)
+            .style(SymbolStyle::Ascii)
+            .to_string(),
+        "5h 55m 10.3s"
+    );
+    assert!(Angle::<HourAngle>::parse_hour_angle("5ʰ55ᵐ10.3ˢ")
+        .unwrap()
+        .approx_eq(&betelgeuse, 1e-12, 0.));
+    assert!(Angle::<Degree>::parse_hour_angle("5x").is_err());
+}
+
+#[test]
+fn compass_points() {
+    assert_eq!(0.deg().compass_point(), "N");
+    assert_eq!(11.deg().compass_point(), "NbE");
+    assert_eq!(225.deg().compass_point(), "SW");
+    assert_eq!((-22.5).deg().compass_point(), "NNW");
+    assert_eq!(355.deg().compass_point(), "N");
+}
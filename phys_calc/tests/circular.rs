@@ -0,0 +1,31 @@
+use phys_calc::literals::*;
+
+#[test]
+fn wrapping() {
+    assert_eq!((350.deg() + 20.deg()).normalized(), 10.deg());
+    assert_eq!((-90).deg().normalized(), 270.deg());
+    assert_eq!(450.grad().normalized(), 50.grad());
+    assert_eq!(270.deg().normalized_signed(), (-90).deg());
+    assert_eq!(180.deg().normalized_signed(), 180.deg());
+    assert_eq!((-180).deg().normalized_signed(), 180.deg());
+    assert!(7
+        .rad()
+        .normalized()
+        .approx_eq(&(7. - std::f64::consts::TAU).rad(), 1e-12, 0.));
+}
+
+#[test]
+fn circular_arithmetic() {
+    assert_eq!(350.deg().difference(10.deg()), 20.deg());
+    assert_eq!(10.deg().difference(350.deg()), (-20).deg());
+    assert!(350
+        .deg()
+        .difference(100.grad())
+        .approx_eq(&100.deg(), 1e-12, 0.));
+    assert_eq!(350.deg().lerp(30.deg(), 0.25), 0.deg());
+
+    let mean = Angle::circular_mean([350.deg(), 30.deg()]).unwrap();
+    assert!(mean.approx_eq(&10.deg(), 1e-9, 0.));
+    assert!(Angle::circular_mean([0.deg(), 180.deg()]).is_none());
+    assert!(Angle::<Degree>::circular_mean([]).is_none());
+}
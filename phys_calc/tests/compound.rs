@@ -0,0 +1,87 @@
+use phys_calc::compound::CompoundParseError;
+use phys_calc::format::SymbolStyle;
+use phys_calc::literals::*;
+
+#[test]
+fn splitting() {
+    let height = 1.8.m();
+    assert_eq!(height.compound::<(Foot, Inch)>().to_string(), "5 ft 11 in");
+    assert_eq!(
+        height.compound::<(Foot, Inch)>().decimals(2).to_string(),
+        "5 ft 10.87 in"
+    );
+    assert_eq!(
+        5400.s().compound::<(Hour, Minute, Second)>().to_string(),
+        "1 hr 30 min 0 s"
+    );
+
+    // Rounding the last link carries into the ones before it.
+    assert_eq!(
+        59.6.s().compound::<(Minute, Second)>().to_string(),
+        "1 min 0 s"
+    );
+    assert_eq!(
+        Angle::<Degree>::new(12.999_99)
+            .compound::<(Degree, Arcminute, Arcsecond)>()
+            .to_string(),
+        "13°0′0″"
+    );
+    // Leading zero components are dropped.
+    assert_eq!(
+        90.s().compound::<(Hour, Minute, Second)>().to_string(),
+        "1 min 30 s"
+    );
+    assert_eq!(0.s().compound::<(Hour, Minute)>().to_string(), "0 min");
+
+    assert_eq!(
+        (-90).s().compound::<(Minute, Second)>().to_string(),
+        "-1 min 30 s"
+    );
+    assert_eq!((-0.1).s().compound::<(Minute, Second)>().to_string(), "0 s");
+
+    assert_eq!(
+        90.s()
+            .compound::<(Minute, Second)>()
+            .style(SymbolStyle::Name)
+            .to_string(),
+        "1 minute 30 seconds"
+    );
+    assert_eq!(
+        format!("{:.1}", 90.25.s().compound::<(Minute, Second)>()),
+        "1 min 30.3 s"
+    );
+}
+
+#[test]
+fn parsing() {
+    let parse = Time::<Second>::parse_compound::<(Hour, Minute, Second)>;
+    assert_eq!(parse("1 hr 30 min").unwrap(), 5400.s());
+    assert_eq!(parse("1 h 30 min").unwrap(), 5400.s());
+    assert_eq!(parse("1h30min").unwrap(), 5400.s());
+    assert_eq!(parse("-2 min 30 s").unwrap(), (-150).s());
+    assert_eq!(parse("1.5e1 s").unwrap(), 15.s());
+
+    assert!(Length::<Meter>::parse_compound::<(Foot, Inch)>("5 ft 3 in")
+        .unwrap()
+        .approx_eq(&Length::<Inch>::new(63.), 1e-12, 0.));
+    assert!(
+        Angle::<Degree>::parse_compound::<(Degree, Arcminute, Arcsecond)>("12°30′")
+            .unwrap()
+            .approx_eq(&12.5.deg(), 1e-12, 0.)
+    );
+
+    assert_eq!(parse(""), Err(CompoundParseError::Empty));
+    assert_eq!(parse("-"), Err(CompoundParseError::Empty));
+    assert_eq!(
+        parse("1 hr 30"),
+        Err(CompoundParseError::MissingUnit("30".into()))
+    );
+    assert_eq!(
+        parse("5 ft"),
+        Err(CompoundParseError::UnknownUnit("ft".into()))
+    );
+    assert_eq!(
+        parse("x s"),
+        Err(CompoundParseError::InvalidNumber("x s".into()))
+    );
+}
@@ -0,0 +1,21 @@
+use phys_calc::base::length::*;
+use phys_calc::base::time::*;
+use phys_calc::math_helpers::*;
+
+#[test]
+fn convert_derived() {
+    let speed: Quotient<Length<Kilometer>, Time<Hour>> = DimensionOp::<_, Per>::apply(
+        Length::<Kilometer>::new(36.).to_derived(),
+        Time::<Hour>::new(1.).to_derived(),
+    );
+    let converted: Quotient<Length<Meter>, Time<Second>> = speed.convert();
+    assert!(converted.approx_eq(&speed, 1e-12, 0.));
+    assert!((converted.value() - 10.).abs() < 1e-12);
+
+    let area: Product<Length<Meter>, Length<Meter>> = DimensionOp::<_, Times>::apply(
+        Length::<Meter>::new(2000.).to_derived(),
+        Length::<Meter>::new(500.).to_derived(),
+    );
+    let converted: Product<Length<Kilometer>, Length<Kilometer>> = area.convert();
+    assert_eq!(converted.to_string(), "1km²");
+}
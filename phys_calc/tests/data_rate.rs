@@ -0,0 +1,32 @@
+use phys_calc::literals::*;
+use phys_calc::prefix::*;
+
+#[test]
+fn data_rates() {
+    assert_eq!(1.Gbps(), DataRate::<Giga<BitPerSecond>>::new(1.));
+    assert_eq!(1.Gbps().value_in::<MegabytePerSecond>(), 125.);
+    assert_eq!(
+        DataRate::<MebibytePerSecond>::new(1.),
+        DataRate::<Mebi<BytePerSecond>>::new(1.)
+    );
+
+    // 40 GiB at 1 Gbps.
+    let time = 40.GiB().transfer_time(1.Gbps());
+    assert!((time.value() - 343.597_383_68).abs() < 1e-9);
+    assert_eq!(1.Gbps().transferred_in(time), 40.GiB());
+
+    let rate = DataRate::<MegabitPerSecond>::from_transfer(100.MB(), 8.s());
+    assert_eq!(rate, 100.Mbps());
+    assert_eq!(100.MB() / 8.s(), 100.Mbps());
+    let download = 4.GiB() / 2.minutes();
+    assert!((download.value_in::<MebibytePerSecond>() - 4096. / 120.).abs() < 1e-9);
+
+    assert_eq!(1.Gbps().engineering().to_string(), "1 Gbit/s");
+    assert_eq!(
+        DataRate::<MebibytePerSecond>::new(512.)
+            .engineering_binary()
+            .to_string(),
+        "512 MiB/s"
+    );
+    assert_eq!(1.Gbps().to_string(), "1Gbps");
+}
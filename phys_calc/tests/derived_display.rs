@@ -0,0 +1,25 @@
+use phys_calc::literals::*;
+use phys_calc::math_helpers::*;
+
+#[test]
+fn derived_display() {
+    let speed = DimensionOp::<_, Per>::apply(12.m().to_derived(), 4.s().to_derived());
+    assert_eq!(speed.to_string(), "3m·s⁻¹");
+    assert_eq!(format!("{speed:#}"), "3m/s");
+    assert_eq!(format!("{speed:e}"), "3e0m·s⁻¹");
+    assert_eq!(format!("{speed:#e}"), "3e0m/s");
+
+    let force = DimensionOp::<_, Per>::apply(
+        DimensionOp::<_, Times>::apply(2.kg().to_derived(), 1500.m().to_derived()),
+        DimensionOp::<_, Times>::apply(1.s().to_derived(), 1.s().to_derived()),
+    );
+    assert_eq!(force.to_string(), "3000kg·m·s⁻²");
+    assert_eq!(format!("{force:#}"), "3000kg m/s^2");
+    assert_eq!(format!("{force:e}"), "3e3kg·m·s⁻²");
+
+    let rate = DimensionOp::<_, Per>::apply(1.m().to_derived(), speed);
+    let per_second = DimensionOp::<_, Per>::apply(rate, rate);
+    let hertz = DimensionOp::<_, Per>::apply(per_second, 1.s().to_derived());
+    assert_eq!(format!("{hertz:#}"), "1/s");
+    assert_eq!(hertz.to_string(), "1s⁻¹");
+}
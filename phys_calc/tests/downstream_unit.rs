@@ -0,0 +1,40 @@
+// Units declared outside phys_calc, the way a dependent crate would.
+use phys_calc::base::length::{Length, Meter};
+use phys_calc::base::time::{Second, Time};
+use phys_calc::Unit;
+
+#[derive(Copy, Clone, Debug, Unit)]
+#[multiplier(201.168)]
+#[unit("fur", latex = "fur", name = "furlong")]
+#[unit_impl(LengthUnit)]
+pub struct Furlong;
+
+#[derive(Copy, Clone, Debug, Unit)]
+#[multiplier(1.7018)]
+#[unit("smoot", latex = "smoot", name = "smoot")]
+#[unit_impl(phys_calc::base::unit_traits::LengthUnit)]
+pub struct Smoot;
+
+mod reexported {
+    pub use phys_calc as physics;
+}
+
+#[derive(Copy, Clone, Debug, Unit)]
+#[multiplier(1209600.)]
+#[unit("ftn", latex = "ftn", name = "fortnight")]
+#[unit_impl(TimeUnit)]
+#[unit_crate(crate::reexported::physics)]
+pub struct Fortnight;
+
+#[test]
+fn converts_downstream_units() {
+    let race = Length::<Furlong>::new(8.);
+    assert!(race.approx_eq(&Length::<Meter>::new(1609.344), 1e-12, 0.));
+    assert_eq!(race.to_string(), "8fur");
+
+    let bridge = 364.4.smoots();
+    assert!((bridge.value_in::<Meter>() - 620.13592).abs() < 1e-9);
+
+    assert_eq!(Time::<Fortnight>::new(1.).value_in::<Second>(), 1209600.);
+    assert_eq!(2.ftn().value(), 2.);
+}
@@ -0,0 +1,77 @@
+// Dimensions registered outside phys_calc, next to the built-in ten.
+use phys_calc::base::time::Second;
+use phys_calc::extra::{Extra, ExtraDimension, ExtraList, Here, Next};
+use phys_calc::math::{NegOne, One, Zero};
+use phys_calc::math_helpers::*;
+use phys_calc::unit::None;
+use phys_calc::Unit;
+
+pub struct Pixels;
+impl ExtraDimension for Pixels {
+    type Position = Here;
+    type Reference = Pixel;
+}
+
+pub struct Currency;
+impl ExtraDimension for Currency {
+    type Position = Next<Here>;
+    type Reference = Dollar;
+}
+
+#[derive(Copy, Clone, Debug, Unit)]
+#[multiplier(1.)]
+#[unit("px", latex = "px", name = "pixel")]
+#[unit_impl(ExtraUnit<Pixels>)]
+pub struct Pixel;
+
+#[derive(Copy, Clone, Debug, Unit)]
+#[multiplier(1000.)]
+#[unit("kpx", latex = "kpx", name = "kilopixel")]
+#[unit_impl(phys_calc::extra::ExtraUnit<Pixels>)]
+pub struct Kilopixel;
+
+#[derive(Copy, Clone, Debug, Unit)]
+#[multiplier(1.)]
+#[unit("$", ascii = "USD", latex = r"\$", name = "dollar")]
+#[unit_impl(ExtraUnit<Currency>)]
+pub struct Dollar;
+
+#[derive(Copy, Clone, Debug, Unit)]
+#[multiplier(0.01)]
+#[unit("¢", ascii = "ct", latex = "ct", name = "cent")]
+#[unit_impl(ExtraUnit<Currency>)]
+pub struct Cent;
+
+type PerSecond<X> = Derived<
+    LengthPower<Zero, None>,
+    TimePower<NegOne, Second>,
+    TempPower<Zero, None>,
+    MassPower<Zero, None>,
+    CurrentPower<Zero, None>,
+    LuminousIntensityPower<Zero, None>,
+    QuantityPower<Zero, None>,
+    AnglePower<Zero, None>,
+    SolidAnglePower<Zero, None>,
+    DigitalInformationPower<Zero, None>,
+    X,
+>;
+
+#[test]
+fn extra_dimensions() {
+    let width: Extra<Pixel> = 1920.px();
+    assert_eq!(width.to_string(), "1920px");
+    assert_eq!(1.92.kpx(), width);
+    assert!(2.kpx() > width);
+
+    let price = 250.ct();
+    assert_eq!(price, 2.5.USD());
+    assert_eq!(price.to_string(), "250¢");
+
+    let throughput = PerSecond::<ExtraList<One, Kilopixel>>::new(3.);
+    assert_eq!(throughput.to_string(), "3kpx·s⁻¹");
+    assert_eq!(throughput.base_value(), 3000.);
+
+    let burn = PerSecond::<ExtraList<One, Dollar>>::new(0.5);
+    assert_eq!(format!("{burn:#}"), "0.5$/s");
+    assert_eq!(burn, PerSecond::<ExtraList<One, Cent>>::new(50.));
+}
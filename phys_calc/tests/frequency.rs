@@ -0,0 +1,31 @@
+use phys_calc::literals::*;
+use phys_calc::prefix::*;
+
+#[test]
+fn frequencies() {
+    assert_eq!(Frequency::<Mega<Hertz>>::new(2.4), 2.4.MHz());
+    assert_eq!(2.4.GHz().engineering().to_string(), "2.4 GHz");
+    assert_eq!(50.Hz().period(), 20.ms());
+    assert!(20.ms().to_frequency().approx_eq(&50.Hz(), 1e-12, 0.));
+    assert_eq!(1.kHz().to_string(), "1kHz");
+}
+
+#[test]
+fn angular_velocities() {
+    let engine = 3000.rpm();
+    assert!(engine.to_frequency().approx_eq(&50.Hz(), 1e-12, 0.));
+    assert!(50.Hz().to_angular_velocity().approx_eq(&engine, 1e-12, 0.));
+    assert!(AngularVelocity::<DegreePerSecond>::new(360.)
+        .convert::<RevolutionPerMinute>()
+        .approx_eq(&60.rpm(), 1e-12, 0.));
+    assert!(1.Hz().to_angular_velocity().approx_eq(
+        &AngularVelocity::<RadianPerSecond>::new(std::f64::consts::TAU),
+        1e-12,
+        0.
+    ));
+
+    // A quarter turn in 5 s, 3 rpm.
+    let sweep = 90.deg() / 5.s();
+    assert!(sweep.approx_eq(&3.rpm(), 1e-12, 0.));
+    assert!((1.turns() / 1.minutes()).approx_eq(&1.rpm(), 1e-12, 0.));
+}
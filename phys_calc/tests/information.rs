@@ -0,0 +1,19 @@
+use phys_calc::literals::*;
+use phys_calc::prefix::*;
+use phys_calc::q;
+
+#[test]
+fn information_units() {
+    // Everything is counted in bits underneath.
+    assert_eq!(1.B().value_in::<Bit>(), 8.);
+    assert_eq!(1.KiB().value_in::<Bit>(), 8192.);
+    assert_eq!(DigitalInformation::<Kibi<Byte>>::new(1.), 1.KiB());
+    assert_eq!(DigitalInformation::<Giga<Bit>>::new(8.), 1.GB());
+    assert_eq!(
+        DigitalInformation::<Yobi<Byte>>::new(1.).to_string(),
+        "1YiB"
+    );
+    assert_eq!(2.nibbles(), 1.B());
+    assert_eq!(1.qword(), 2.dword());
+    assert_eq!(q!(4 word), 8.B());
+}
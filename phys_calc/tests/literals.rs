@@ -0,0 +1,35 @@
+use phys_calc::literals::*;
+
+#[test]
+fn literal_methods() {
+    // Every number type, through the symbol and the plural name.
+    assert_eq!(5.km(), Length::<Kilometer>::new(5.));
+    assert_eq!(5_i64.km(), 5.kilometers());
+    assert_eq!(5_u32.km(), 5_u64.km());
+    assert_eq!(2.5_f32.km(), 2.5.km());
+    assert_eq!(3.minutes(), 180.s());
+    assert_eq!(1.um(), 1.micrometers());
+    assert_eq!(Temperature::<Celsius>::new(20.), 20.degC());
+    assert_eq!(20.degrees_celsius(), 20.degC());
+}
+
+// The literal traits don't take over methods numbers already have.
+#[test]
+fn no_shadowing() {
+    let three = std::hint::black_box(3);
+    assert_eq!(5.min(three), 3);
+    assert_eq!(5.0_f64.min(3.), 3.);
+    assert_eq!(5.max(three), 5);
+    assert_eq!((-5_i32).abs(), 5);
+}
+
+// Each unit has its own trait, importable without the rest.
+mod single_trait {
+    use phys_calc::base::length::{Length, Meter, MeterLiteral};
+
+    #[test]
+    fn single_trait() {
+        assert_eq!(400.m(), Length::<Meter>::new(400.));
+        assert_eq!(MeterLiteral::meters(2), 2.m());
+    }
+}
@@ -0,0 +1,42 @@
+use phys_calc::derived::volume::{Volume, VolumeUnit};
+use phys_calc::derived::Kinematic;
+use phys_calc::literals::*;
+use phys_calc::math::{NegOne, One};
+use phys_calc::math_helpers::*;
+use phys_calc::Unit;
+
+// Named dimensions take units from other crates like the base ones do.
+#[derive(Copy, Clone, Debug, Unit)]
+#[multiplier(2.365882365e-4)]
+#[unit("cup", latex = "cup", name = "cup")]
+#[unit_impl(VolumeUnit)]
+#[unit_quantity(Volume)]
+pub struct Cup;
+
+#[test]
+fn named_dimensions() {
+    let speed = 36.km_per_h();
+    let knots: Velocity<Knot> = speed.convert();
+    assert!(knots.approx_eq(&10.meters_per_second(), 1e-12, 0.));
+    let back: Kinematic<One, Kilometer, NegOne, Hour> = knots.to_derived().convert();
+    assert!(back.approx_eq(&speed.to_derived(), 1e-12, 0.));
+    let fall: Acceleration<FootPerSecondSquared> = 9.80665.m_per_s2().convert();
+    assert!((fall.value() - 32.174).abs() < 1e-3);
+
+    let side = 100.m().to_derived();
+    let field: Area<Hectare> = DimensionOp::<_, Times>::apply(side, side).convert();
+    assert_eq!(field.to_string(), "1ha");
+    assert!(1
+        .ac()
+        .convert::<SquareMeter>()
+        .approx_eq(&4046.8564224.m2(), 1e-12, 0.));
+
+    // The prefix of an area goes on the meter, squared with it.
+    assert_eq!(1500.m2().engineering().to_string(), "1500 m²");
+    assert_eq!(2.km2().engineering().to_string(), "2 km²");
+    assert_eq!(50.cm2().engineering().to_string(), "5000 mm²");
+
+    assert_eq!(2500.L().to_string(), "2500L");
+    assert_eq!(2500.L().engineering().to_string(), "2.5 kL");
+    assert_eq!(1.gal().convert::<Cup>().round().value(), 16.);
+}
@@ -0,0 +1,50 @@
+use phys_calc::literals::*;
+use phys_calc::math_helpers::*;
+
+#[test]
+fn operators() {
+    assert_eq!(-3.m(), (-3).m());
+    assert_eq!(7.m() % 3.m(), 1.m());
+    let mut rest = 10.s();
+    rest %= 4.s();
+    assert_eq!(rest, 2.s());
+    assert_eq!(2. * 3.kg(), 6.kg());
+    assert_eq!(3.kg() * 2., 6.kg());
+    assert_eq!(6.kg() / 4., 1.5.kg());
+}
+
+#[test]
+fn sum_and_product() {
+    let laps = [1.km(), 2.km(), 0.5.km()];
+    assert_eq!(laps.iter().sum::<Length<Kilometer>>(), 3.5.km());
+    assert_eq!(laps.into_iter().sum::<Length<Kilometer>>(), 3.5.km());
+    assert_eq!(
+        Vec::<Time<Second>>::new().into_iter().sum::<Time<Second>>(),
+        0.s()
+    );
+
+    let ratio = |a: Length<Meter>, b: Length<Meter>| {
+        DimensionOp::<_, Per>::apply(a.to_derived(), b.to_derived())
+    };
+    let gain = [ratio(2.m(), 1.m()), ratio(3.m(), 1.m())]
+        .into_iter()
+        .product::<Quotient<Length<Meter>, Length<Meter>>>();
+    assert_eq!(gain, ratio(6.m(), 1.m()));
+}
+
+#[test]
+fn helpers() {
+    assert_eq!((-2.5).m().abs(), 2.5.m());
+    assert_eq!(2.m().min(3.m()), 2.m());
+    assert_eq!(2.m().max(3.m()), 3.m());
+    assert_eq!(5.m().clamp(0.m(), 4.m()), 4.m());
+    assert_eq!((-5).m().signum(), -1.);
+    assert_eq!(2.5.m().floor(), 2.m());
+    assert_eq!(2.5.m().ceil(), 3.m());
+    assert_eq!(2.5.m().round(), 3.m());
+    assert_eq!(90.s().round_in::<Minute>(), 120.s());
+    assert_eq!(90.s().floor_in::<Minute>(), 60.s());
+    assert_eq!(61.s().ceil_in::<Minute>(), 120.s());
+    assert!(!Length::<Meter>::new(f64::INFINITY).is_finite());
+    assert!(Length::<Meter>::new(f64::NAN).is_nan());
+}
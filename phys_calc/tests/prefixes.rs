@@ -0,0 +1,44 @@
+use phys_calc::format::SymbolStyle;
+use phys_calc::literals::*;
+use phys_calc::prefix::*;
+
+#[test]
+fn prefixed_units() {
+    let distance = Length::<Mega<Meter>>::new(2.);
+    assert_eq!(distance.to_string(), "2Mm");
+    assert_eq!(distance, 2000.km());
+
+    let current = Current::<Micro<Ampere>>::new(5.);
+    assert_eq!(current.styled(SymbolStyle::Ascii).to_string(), "5uA");
+    assert!(current.approx_eq(&Current::<Milliampere>::new(0.005), 1e-12, 0.));
+
+    let brightness = LuminousIntensity::<Micro<Candela>>::new(3.);
+    assert_eq!(
+        brightness.styled(SymbolStyle::Name).to_string(),
+        "3 microcandelas"
+    );
+
+    assert_eq!(
+        Volume::<Kilo<Liter>>::new(1.)
+            .convert::<CubicMeter>()
+            .value(),
+        1.
+    );
+    assert_eq!(Time::<Giga<Second>>::new(1.).value_in::<Second>(), 1e9);
+    assert_eq!(
+        Length::<Quecto<Meter>>::new(1.)
+            .styled(SymbolStyle::Latex)
+            .to_string(),
+        r"\SI{1}{\quecto\metre}"
+    );
+}
+
+#[test]
+fn prefixed_symbols_are_built_once() {
+    let first = <Kilo<Meter> as LengthUnit>::symbols();
+    let second = <Kilo<Meter> as LengthUnit>::symbols();
+    assert!(std::ptr::eq(first.unicode, second.unicode));
+    assert!(std::ptr::eq(first.plural, second.plural));
+    assert_eq!(<Kilo<Meter> as LengthUnit>::unit(), "km");
+    assert_eq!(<Kibi<Byte> as DigitalInformationUnit>::unit(), "KiB");
+}
@@ -0,0 +1,5 @@
+#[test]
+fn quantity_literals() {
+    let t = trybuild::TestCases::new();
+    t.pass("tests/ui/pass/*.rs");
+}
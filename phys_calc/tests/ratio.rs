@@ -0,0 +1,30 @@
+use phys_calc::literals::*;
+use phys_calc::math::number;
+
+#[test]
+fn ratios() {
+    let share = 30.km() / 40.km();
+    assert_eq!(share.to_string(), "0.75");
+    assert_eq!(share.convert::<Percent>().to_string(), "75%");
+    assert_eq!(share.engineering().to_string(), "0.75");
+    assert_eq!(number::from(share), 0.75);
+    assert_eq!(number::from(3.pct()), 0.03);
+
+    let co2 = 250.ppm();
+    assert!(co2.approx_eq(&0.025.pct(), 1e-12, 0.));
+    assert!(co2
+        .convert::<PartPerBillion>()
+        .approx_eq(&250_000.ppb(), 1e-12, 0.));
+    assert_eq!(1.5.permille().to_string(), "1.5‰");
+    assert_eq!(1.ppt().value_in::<PartPerMillion>(), 1e-6);
+    assert_eq!(1.ppt().value_in::<Fraction>(), 1e-12);
+}
+
+#[test]
+fn ratio_of_different_units() {
+    let share = 3.km() / 750.m().convert::<Kilometer>();
+    assert!(share.approx_eq(&Ratio::<Fraction>::new(4.), 1e-12, 0.));
+    assert!((1.5.km().convert::<Meter>() / 300.m())
+        .convert::<Percent>()
+        .approx_eq(&500.pct(), 1e-12, 0.));
+}
@@ -0,0 +1,77 @@
+use phys_calc::format::{CompositeStyle, SymbolStyle};
+use phys_calc::literals::*;
+use phys_calc::math_helpers::*;
+use phys_calc::prefix::*;
+
+#[test]
+fn symbol_styles() {
+    let length = 5.um();
+    assert_eq!(length.styled(SymbolStyle::Unicode).to_string(), "5μm");
+    assert_eq!(length.styled(SymbolStyle::Ascii).to_string(), "5um");
+    assert_eq!(
+        length.styled(SymbolStyle::Latex).to_string(),
+        r"\SI{5}{\micro\metre}"
+    );
+    assert_eq!(
+        length.styled(SymbolStyle::Name).to_string(),
+        "5 micrometers"
+    );
+    assert_eq!(1.um().styled(SymbolStyle::Name).to_string(), "1 micrometer");
+}
+
+#[test]
+fn non_si_latex() {
+    assert_eq!(
+        3.ft().styled(SymbolStyle::Latex).to_string(),
+        r"\SI{3}{\text{ft}}"
+    );
+    assert_eq!(
+        Temperature::<Fahrenheit>::new(451.)
+            .styled(SymbolStyle::Latex)
+            .to_string(),
+        r"\SI{451}{\degree\text{F}}"
+    );
+    assert_eq!(
+        Length::<Kilo<Foot>>::new(2.)
+            .styled(SymbolStyle::Latex)
+            .to_string(),
+        r"\SI{2}{\kilo\text{ft}}"
+    );
+    assert_eq!(
+        2.ft2().styled(SymbolStyle::Latex).to_string(),
+        r"\SI{2}{\text{ft}\squared}"
+    );
+}
+
+#[test]
+fn composite_styles() {
+    let speed = DimensionOp::<_, Per>::apply(10.m().to_derived(), 2.s().to_derived());
+    let symbol = |style| {
+        (
+            speed.styled(style).to_string(),
+            speed
+                .styled(style)
+                .composite(CompositeStyle::Slash)
+                .to_string(),
+        )
+    };
+    assert_eq!(
+        symbol(SymbolStyle::Unicode),
+        ("5m·s⁻¹".into(), "5m/s".into())
+    );
+    assert_eq!(
+        symbol(SymbolStyle::Ascii),
+        ("5m*s^-1".into(), "5m/s".into())
+    );
+    assert_eq!(
+        symbol(SymbolStyle::Latex),
+        (
+            r"\SI{5}{\metre\per\second}".into(),
+            r"\SI{5}{\metre\per\second}".into()
+        )
+    );
+    assert_eq!(
+        symbol(SymbolStyle::Name),
+        ("5 meters per second".into(), "5 meters per second".into())
+    );
+}
@@ -0,0 +1,33 @@
+use phys_calc::base::temperature::BelowAbsoluteZero;
+use phys_calc::literals::*;
+
+#[test]
+fn try_new() {
+    assert_eq!(
+        Temperature::<Kelvin>::try_new(0.),
+        Ok(Temperature::<Kelvin>::new(0.))
+    );
+    assert_eq!(Temperature::<Kelvin>::try_new(-0.1), Err(BelowAbsoluteZero));
+    assert!(Temperature::<Celsius>::try_new(-273.15).is_ok());
+    assert_eq!(
+        Temperature::<Celsius>::try_new(-274.),
+        Err(BelowAbsoluteZero)
+    );
+    assert!(Temperature::<Fahrenheit>::try_new(-459.).is_ok());
+    assert_eq!(
+        Temperature::<Fahrenheit>::try_new(-460.),
+        Err(BelowAbsoluteZero)
+    );
+    assert_eq!(
+        BelowAbsoluteZero.to_string(),
+        "temperature is below absolute zero"
+    );
+}
+
+// Only `try_new` checks, differences are allowed to be negative.
+#[test]
+fn unchecked() {
+    let drop = Temperature::<Kelvin>::new(10.) - Temperature::<Kelvin>::new(25.);
+    assert_eq!(drop.value(), -15.);
+    assert_eq!((-Temperature::<Kelvin>::new(5.)).value(), -5.);
+}
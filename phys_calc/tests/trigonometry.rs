@@ -0,0 +1,23 @@
+use phys_calc::literals::*;
+
+#[test]
+fn trigonometry() {
+    assert!((30.deg().sin() - 0.5).abs() < 1e-12);
+    assert!((100.grad().cos()).abs() < 1e-12);
+    assert!((45.deg().tan() - 1.).abs() < 1e-12);
+    let (sin, cos) = 60.deg().sin_cos();
+    assert!((sin - 3f64.sqrt() / 2.).abs() < 1e-12);
+    assert!((cos - 0.5).abs() < 1e-12);
+    assert_eq!(0.rad().sinh(), 0.);
+    assert_eq!(0.rad().cosh(), 1.);
+
+    assert!(Angle::asin(0.5)
+        .convert::<Degree>()
+        .approx_eq(&30.deg(), 1e-12, 0.));
+    assert!(Angle::acos(0.).approx_eq(&90.deg(), 1e-12, 0.));
+    assert!(Angle::atan2(-1., -1.)
+        .convert::<Degree>()
+        .approx_eq(&(-135).deg(), 1e-12, 0.));
+    assert!(Angle::atanh(45.deg().tanh()).approx_eq(&45.deg(), 1e-12, 0.));
+    assert!(Angle::asinh(1.rad().sinh()).approx_eq(&1.rad(), 1e-12, 0.));
+}
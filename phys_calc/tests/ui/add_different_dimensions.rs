@@ -0,0 +1,6 @@
+use phys_calc::base::length::*;
+use phys_calc::base::time::*;
+
+fn main() {
+    let _ = Length::<Meter>::new(1.) + Time::<Second>::new(1.);
+}
@@ -0,0 +1,9 @@
+use phys_calc::base::temperature::*;
+use phys_calc::math_helpers::*;
+
+fn main() {
+    let hot = Temperature::<Fahrenheit>::new(100.).to_derived();
+    let warm = Temperature::<Kelvin>::new(300.).to_derived();
+    let _ = hot > warm;
+    let _ = hot.approx_eq(&warm, 1e-9, 0.);
+}
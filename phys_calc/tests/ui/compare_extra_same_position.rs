@@ -0,0 +1,31 @@
+use phys_calc::extra::{Extra, ExtraDimension, Here};
+use phys_calc::Unit;
+
+pub struct Pixels;
+impl ExtraDimension for Pixels {
+    type Position = Here;
+    type Reference = Pixel;
+}
+
+// Registered at the same position as `Pixels` by mistake.
+pub struct Currency;
+impl ExtraDimension for Currency {
+    type Position = Here;
+    type Reference = Dollar;
+}
+
+#[derive(Copy, Clone, Debug, Unit)]
+#[multiplier(1.)]
+#[unit("px", latex = r"\text{px}", name = "pixel")]
+#[unit_impl(ExtraUnit<Pixels>)]
+pub struct Pixel;
+
+#[derive(Copy, Clone, Debug, Unit)]
+#[multiplier(1.)]
+#[unit("$", ascii = "USD", latex = r"\$", name = "dollar")]
+#[unit_impl(ExtraUnit<Currency>)]
+pub struct Dollar;
+
+fn main() {
+    let _ = Extra::<Pixel>::new(3.) == Extra::<Dollar>::new(3.);
+}
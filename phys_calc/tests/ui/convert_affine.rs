@@ -0,0 +1,11 @@
+use phys_calc::base::temperature::*;
+use phys_calc::base::time::*;
+use phys_calc::math_helpers::*;
+
+fn main() {
+    let rate: Quotient<Temperature<Celsius>, Time<Second>> = DimensionOp::<_, Per>::apply(
+        Temperature::<Celsius>::new(3.).to_derived(),
+        Time::<Second>::new(1.).to_derived(),
+    );
+    let _: Quotient<Temperature<Kelvin>, Time<Second>> = rate.convert();
+}
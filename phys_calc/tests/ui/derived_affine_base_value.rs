@@ -0,0 +1,8 @@
+use phys_calc::literals::*;
+use phys_calc::math_helpers::*;
+
+fn main() {
+    let warming = DimensionOp::<_, Per>::apply(100.degF().to_derived(), 1.s().to_derived());
+    let _ = warming.engineering();
+    let _ = warming.base_value();
+}
@@ -0,0 +1,5 @@
+use phys_calc::literals::*;
+
+fn main() {
+    let _ = 3.km() / 750.m();
+}
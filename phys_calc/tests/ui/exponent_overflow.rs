@@ -0,0 +1,9 @@
+use phys_calc::base::length::*;
+use phys_calc::math_helpers::*;
+
+fn main() {
+    let l = Length::<Meter>::new(2.).to_derived();
+    let area = DimensionOp::<_, Times>::apply(l, l);
+    let volume = DimensionOp::<_, Times>::apply(area, l);
+    let _ = DimensionOp::<_, Times>::apply(volume, volume);
+}
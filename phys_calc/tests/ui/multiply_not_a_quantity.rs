@@ -0,0 +1,6 @@
+use phys_calc::base::length::*;
+use phys_calc::math_helpers::*;
+
+fn main() {
+    let _ = Length::<Meter>::new(1.).multiply(2.);
+}
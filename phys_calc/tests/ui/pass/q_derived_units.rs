@@ -0,0 +1,17 @@
+use phys_calc::literals::*;
+use phys_calc::math_helpers::UnitToDerived;
+use phys_calc::q;
+
+fn main() {
+    assert_eq!(q!(9.81 m/s^2), 9.81.m_per_s2().to_derived());
+    assert_eq!(q!("9.81 m·s⁻²"), 9.81.m_per_s2().to_derived());
+    assert!(q!(3 ft/s).approx_eq(&3.ft_per_s().to_derived(), 1e-12, 0.));
+    assert_eq!(q!(5 /s).to_string(), "5s⁻¹");
+
+    let force = q!(2 kg m/s^2);
+    assert_eq!(force.to_string(), "2kg·m·s⁻²");
+
+    // Named units in a compound unit go through their base units.
+    let flow = q!(2 L/s);
+    assert!((flow.base_value() - 2e-3).abs() < 1e-15);
+}
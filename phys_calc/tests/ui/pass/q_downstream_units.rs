@@ -0,0 +1,14 @@
+use phys_calc::base::length::{Length, Meter};
+use phys_calc::{q, Unit};
+
+#[derive(Copy, Clone, Debug, Unit)]
+#[multiplier(201.168)]
+#[unit("fur", latex = r"\text{fur}", name = "furlong")]
+#[unit_impl(LengthUnit)]
+pub struct Furlong;
+
+fn main() {
+    assert_eq!(q!(8 fur), Length::<Furlong>::new(8.));
+    assert!(q!(8 fur).approx_eq(&Length::<Meter>::new(1609.344), 1e-12, 0.));
+    assert_eq!(q!(2 fur/s).to_string(), "2fur·s⁻¹");
+}
@@ -0,0 +1,19 @@
+use phys_calc::literals::*;
+use phys_calc::q;
+
+fn main() {
+    assert_eq!(q!(2 L), 2.L());
+    assert_eq!(q!(250 mL), 250.mL());
+    assert_eq!(q!(3 ha), 3.ha());
+    assert_eq!(q!(12 kn), 12.kn());
+    assert_eq!(q!(50 Hz), 50.Hz());
+    assert_eq!(q!(33 rpm), 33.rpm());
+    assert_eq!(q!(5 %), 5.pct());
+    assert_eq!(q!(3 ppm), 3.ppm());
+    assert_eq!(q!("1.5 ‰"), 1.5.permille());
+    assert_eq!(q!(4 word), 8.B());
+    assert_eq!(q!(90 min), 1.5.hr());
+    assert_eq!(q!(2 h), 2.hr());
+    assert_eq!(q!("20 °C"), 20.degC());
+    assert_eq!(q!(20 degC), 20.degC());
+}
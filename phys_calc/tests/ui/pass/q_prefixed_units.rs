@@ -0,0 +1,14 @@
+use phys_calc::literals::*;
+use phys_calc::math_helpers::UnitToDerived;
+use phys_calc::q;
+
+fn main() {
+    assert_eq!(q!(3 km), 3.km());
+    assert_eq!(q!("5 μm"), 5.um());
+    assert_eq!(q!(5 um), 5.um());
+    assert_eq!(q!(2 mA), 2.mA());
+    assert_eq!(q!(100 Mbps), 100.Mbps());
+    assert_eq!(q!(4 GiB), 4.GiB());
+    assert!(q!(72 km/h).approx_eq(&72.km_per_h().to_derived(), 1e-12, 0.));
+    assert!(q!(72 km/h).approx_eq(&20.m_per_s().to_derived(), 1e-12, 0.));
+}
@@ -0,0 +1,7 @@
+use phys_calc::literals::*;
+use phys_calc::prefix::Kilo;
+
+fn main() {
+    let _ = Length::<Kilo<Kilometer>>::new(1.);
+    let _ = Volume::<Kilo<CubicMeter>>::new(1.);
+}
@@ -0,0 +1,7 @@
+use phys_calc::base::length::*;
+use phys_calc::base::time::*;
+use phys_calc::math_helpers::*;
+
+fn main() {
+    let _ = Length::<Meter>::new(1.).multiply(Time::<Second>::new(1.));
+}
@@ -0,0 +1,5 @@
+use phys_calc::q;
+
+fn main() {
+    let _ = q!(3 furlong);
+}
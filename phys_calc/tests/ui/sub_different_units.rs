@@ -0,0 +1,5 @@
+use phys_calc::base::length::*;
+
+fn main() {
+    let _ = Length::<Meter>::new(1.) - Length::<Kilometer>::new(1.);
+}
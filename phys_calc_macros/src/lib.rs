@@ -4,64 +4,203 @@ use proc_macro::TokenStream;
 use proc_macro2::Span;
 use quote::quote;
 use syn::{
-    parse::Parse, parse_macro_input, punctuated::Punctuated, DeriveInput, Ident, LitStr, Path,
-    Token, TypeParam,
+    parse::Parse, parse_macro_input, punctuated::Punctuated, Attribute, DeriveInput, Ident, LitStr,
+    Path, Token, TypeParam,
 };
 
-#[proc_macro_derive(Unit, attributes(multiplier, unit, unit_impl))]
+mod quantity_literal;
+mod units;
+
+use units::Dimension;
+
+/// A quantity from its usual notation, `q!(9.81 m/s^2)` or `q!("20 °C")` for
+/// symbols that aren't Rust tokens. A single unit gives its quantity
+/// (`Length<Kilometer>`), anything else a `Derived`.
+///
+/// Units are found through their literal traits, so `#[derive(Unit)]` units of
+/// other crates work once their `<Name>Literal` trait is imported. Symbols
+/// that carry an exponent are read as one, `deg²` is a squared degree rather
+/// than `SquareDegree`.
+#[proc_macro]
+pub fn q(ts: TokenStream) -> TokenStream {
+    quantity_literal::expand(ts.into())
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Declares a unit of one of phys_calc's dimensions. Usable from any crate
+/// that depends on `phys_calc`:
+///
+/// ```ignore
+/// use phys_calc::Unit;
+///
+/// #[derive(Copy, Clone, Debug, Unit)]
+/// #[multiplier(201.168)]
+/// #[unit("fur", latex = "fur", name = "furlong")]
+/// #[unit_impl(LengthUnit)]
+/// pub struct Furlong;
+///
+/// let track = phys_calc::base::length::Length::<Furlong>::new(8.);
+/// let same = 8.fur();
+/// ```
+///
+/// - `#[multiplier(..)]`: how many base units (meter, second, gram, ...) one
+///   of this unit is.
+/// - `#[unit("symbol", ascii = "..", latex = "..", name = "..", plural = "..")]`:
+///   `ascii` is required if the symbol isn't ASCII, `plural` defaults to
+///   `name` with an `s`.
+/// - `#[unit_impl(..)]`: one of the traits in `phys_calc::base::unit_traits`,
+///   or `ExtraUnit<Dimension>` for a dimension registered through
+///   `phys_calc::extra::ExtraDimension`.
+/// - `#[unit_quantity(Quantity)]`: for the unit trait of a named derived
+///   dimension, e.g. `#[unit_impl(VolumeUnit)]` with `#[unit_quantity(Volume)]`.
+///   The multiplier is then relative to the dimension's base unit (m³, m/s, ...).
+/// - `#[unit_crate(path)]`: where phys_calc lives if it isn't `::phys_calc`,
+///   e.g. when it is re-exported from another crate.
+///
+/// Besides the unit trait this generates a `<Name>Literal` extension trait
+/// for numbers, `Furlong` gets `FurlongLiteral` with `fur()` and `furlongs()`.
+/// Names numbers already have methods for are left out, so minutes are
+/// `minutes()` but not `min()`. The trait also has hidden methods for the
+/// symbols, which is how `q!` finds the unit. Units named with a prefix
+/// (kilometer) or whose symbol starts with a power (m²) can't take another
+/// one through `phys_calc::prefix`.
+#[proc_macro_derive(
+    Unit,
+    attributes(multiplier, unit, unit_impl, unit_crate, unit_quantity)
+)]
 pub fn derive_unit(ts: TokenStream) -> TokenStream {
-    let input: DeriveInput = syn::parse(ts).expect("Invalid proc macro input for derive!");
+    let input = parse_macro_input!(ts as DeriveInput);
+    expand_unit(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+fn find_attr<'a>(attrs: &'a [Attribute], name: &str, ident: &Ident) -> syn::Result<&'a Attribute> {
+    attrs
+        .iter()
+        .find(|attr| attr.path().is_ident(name))
+        .ok_or_else(|| {
+            syn::Error::new(
+                ident.span(),
+                format!("missing `#[{name}(...)]` attribute on unit `{ident}`"),
+            )
+        })
+}
+
+fn expand_unit(input: DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
     let ident = input.ident;
     let generics = input.generics;
     let attrs = input.attrs;
 
+    if !matches!(input.data, syn::Data::Struct(_)) {
+        return Err(syn::Error::new(
+            ident.span(),
+            "`Unit` can only be derived for structs",
+        ));
+    }
+
     let generics_idents = generics
         .params
         .iter()
         .map(|p| match p {
-            syn::GenericParam::Type(t) => t.ident.clone(),
-            _ => unimplemented!(),
+            syn::GenericParam::Type(t) => Ok(t.ident.clone()),
+            param => Err(syn::Error::new_spanned(
+                param,
+                "units can only have type parameters",
+            )),
         })
-        .collect::<Punctuated<_, Token![,]>>();
-
-    let mult = if let Some(mult) = attrs.iter().find(|attr| attr.path().is_ident("multiplier")) {
-        match mult.meta.clone() {
-            syn::Meta::List(mult) => mult.tokens,
+        .collect::<syn::Result<Punctuated<_, Token![,]>>>()?;
 
-            _ => panic!("Expected #[multiplier(<multiplier>)]"),
+    let mult = match &find_attr(&attrs, "multiplier", &ident)?.meta {
+        syn::Meta::List(mult) if !mult.tokens.is_empty() => mult.tokens.clone(),
+        meta => {
+            return Err(syn::Error::new_spanned(
+                meta,
+                "expected `#[multiplier(<multiplier>)]`",
+            ))
         }
-    } else {
-        panic!("Expected `multiplier` attribute!")
     };
 
-    let unit = if let Some(unit) = attrs.iter().find(|attr| attr.path().is_ident("unit")) {
-        match unit.meta.clone() {
-            syn::Meta::List(marker) => {
-                if let Ok(lit) = syn::parse2::<LitStr>(marker.tokens) {
-                    lit
-                } else {
-                    panic!("Expected string literal as unit!")
-                }
-            }
-            _ => panic!("Expected #[unit(<unit>)]"),
+    let unit_attr = find_attr(&attrs, "unit", &ident)?;
+    let UnitAttr {
+        symbol,
+        ascii,
+        latex,
+        name,
+        plural,
+    } = unit_attr.parse_args::<UnitAttr>()?;
+    let ascii = match ascii {
+        Some(ascii) => ascii,
+        None if symbol.value().is_ascii() => symbol.clone(),
+        None => {
+            return Err(syn::Error::new(
+                symbol.span(),
+                format!(
+                    "unit symbol `{}` is not ASCII, add an `ascii = \"...\"` form",
+                    symbol.value()
+                ),
+            ))
         }
-    } else {
-        panic!("Expected `unit` attribute!")
     };
+    let missing = |form: &str| {
+        syn::Error::new_spanned(
+            unit_attr,
+            format!("missing `{form} = \"...\"` form in `#[unit(...)]`"),
+        )
+    };
+    let latex = latex.ok_or_else(|| missing("latex"))?;
+    let name = name.ok_or_else(|| missing("name"))?;
+    let plural = plural.unwrap_or_else(|| LitStr::new(&format!("{}s", name.value()), name.span()));
 
-    let marker = if let Some(marker) = attrs.iter().find(|attr| attr.path().is_ident("unit_impl")) {
-        match marker.meta.clone() {
-            syn::Meta::List(marker) => {
-                if let Ok(lit) = syn::parse2::<Path>(marker.tokens) {
-                    lit
-                } else {
-                    panic!("Expected trait path for marker trait!")
-                }
-            }
-            _ => panic!("Expected #[marker(<MarkerTrait>)]"),
+    // `Kilo<Kilometer>` would be "kkm" and `Kilo<SquareMeter>` 1000 m², not
+    // the 1e6 m² a km² is.
+    let prefixed = PREFIX_NAMES
+        .iter()
+        .any(|prefix| name.value().to_lowercase().starts_with(prefix));
+    let power = ascii
+        .value()
+        .split(['/', '*', '.'])
+        .next()
+        .is_some_and(|term| term.ends_with(|c: char| c.is_ascii_digit()));
+
+    let krate = match attrs.iter().find(|attr| attr.path().is_ident("unit_crate")) {
+        Some(attr) => attr.parse_args::<Path>()?,
+        None => syn::parse_quote!(::phys_calc),
+    };
+
+    let marker = find_attr(&attrs, "unit_impl", &ident)?.parse_args::<Path>()?;
+    let named = attrs
+        .iter()
+        .find(|attr| attr.path().is_ident("unit_quantity"));
+    let (marker, dimension_type, quantity) = match named {
+        Some(attr) => {
+            let quantity = attr.parse_args::<Path>()?;
+            (quote!(#marker), quote!(), quote!(#quantity))
         }
+        None => match unit_trait(&marker)? {
+            UnitTrait::Base(dimension) => {
+                let module = Ident::new(dimension.module(), Span::call_site());
+                let quantity = Ident::new(dimension.quantity(), Span::call_site());
+                // A bare `LengthUnit` doesn't have to be in scope.
+                let marker = match marker.get_ident() {
+                    Some(marker) => quote!(#krate::base::unit_traits::#marker),
+                    None => quote!(#marker),
+                };
+                (marker, quote!(), quote!(#krate::base::#module::#quantity))
+            }
+            UnitTrait::Extra(dimension) => (
+                quote!(#krate::extra::ExtraUnit),
+                quote!(type Dimension = #dimension;),
+                quote!(#krate::extra::Extra),
+            ),
+        },
+    };
+
+    let literals = if generics.params.is_empty() {
+        literal_trait(&krate, &ident, &quantity, &symbol, &ascii, &plural)
     } else {
-        panic!("Expected `marker` attribute!");
+        quote!()
     };
 
     let mut generics_with_rhs = generics.clone();
@@ -72,43 +211,203 @@ pub fn derive_unit(ts: TokenStream) -> TokenStream {
             Span::call_site(),
         ))));
 
-    quote! {
-        impl crate::Unit for #ident {}
+    let prefixable = match prefixed || power {
+        true => quote!(),
+        false => quote!(impl #generics #krate::unit::Prefixable for #ident #generics_idents {}),
+    };
+
+    Ok(quote! {
+        impl #krate::Unit for #ident {}
+        impl #krate::unit::LinearUnit for #ident {}
+        #prefixable
         impl #generics #marker for #ident #generics_idents {
-            fn mult() -> number {
-                (#mult) as f64
+            #dimension_type
+            fn mult() -> #krate::math::number {
+                (#mult) as #krate::math::number
             }
             fn unit() -> &'static str {
-                #unit
+                #symbol
+            }
+            fn symbols() -> #krate::unit::Symbols {
+                #krate::unit::Symbols {
+                    unicode: #symbol,
+                    ascii: #ascii,
+                    latex: #latex,
+                    singular: #name,
+                    plural: #plural,
+                }
             }
         }
-        impl #generics crate::math::EqualsOrZero for #ident #generics_idents {
+        impl #generics #krate::math::EqualsOrZero for #ident #generics_idents {
             type SelfType = Self;
         }
-        impl #generics crate::math::EqualsOrZero<None> for #ident #generics_idents {
+        impl #generics #krate::math::EqualsOrZero<#krate::unit::None> for #ident #generics_idents {
             type SelfType = Self;
         }
-        impl #generics_with_rhs std::ops::Mul<Rhs> for #ident #generics_idents
-        where Self: MulHelper<Rhs>
+        impl #generics_with_rhs ::std::ops::Mul<Rhs> for #ident #generics_idents
+        where Self: #krate::math_helpers::MulHelper<Rhs>
         {
-            type Output = <Self as MulHelper<Rhs>>::Output;
+            type Output = <Self as #krate::math_helpers::MulHelper<Rhs>>::Output;
             fn mul(self, rhs: Rhs) -> Self::Output {
-                self.multiply(rhs)
+                #krate::math_helpers::MulHelper::multiply(self, rhs)
             }
         }
-        impl #generics_with_rhs std::ops::Div<Rhs> for #ident #generics_idents
-        where Self: DivHelper<Rhs>
+        impl #generics_with_rhs ::std::ops::Div<Rhs> for #ident #generics_idents
+        where Self: #krate::math_helpers::DivHelper<Rhs>
         {
-            type Output = <Self as DivHelper<Rhs>>::Output;
+            type Output = <Self as #krate::math_helpers::DivHelper<Rhs>>::Output;
             fn div(self, rhs: Rhs) -> Self::Output {
-                self.divide(rhs)
+                #krate::math_helpers::DivHelper::divide(self, rhs)
             }
         }
+        #literals
+    })
+}
 
+enum UnitTrait {
+    Base(Dimension),
+    // `ExtraUnit<Pixels>`, a unit of a user-registered dimension.
+    Extra(proc_macro2::TokenStream),
+}
 
+fn unit_trait(marker: &Path) -> syn::Result<UnitTrait> {
+    let last = marker.segments.last();
+    if let Some(segment) = last.filter(|segment| segment.ident == "ExtraUnit") {
+        return match &segment.arguments {
+            syn::PathArguments::AngleBracketed(args) if args.args.len() == 1 => {
+                match &args.args[0] {
+                    syn::GenericArgument::Type(dimension) => {
+                        Ok(UnitTrait::Extra(quote!(#dimension)))
+                    }
+                    arg => Err(syn::Error::new_spanned(
+                        arg,
+                        "expected the dimension's type",
+                    )),
+                }
+            }
+            _ => Err(syn::Error::new_spanned(
+                segment,
+                "expected `ExtraUnit<Dimension>`",
+            )),
+        };
+    }
+    let marker_name = last
+        .map(|segment| segment.ident.to_string())
+        .unwrap_or_default();
+    Dimension::from_unit_trait(&marker_name)
+        .map(UnitTrait::Base)
+        .ok_or_else(|| {
+            let traits = Dimension::ALL.map(|dimension| format!("{}Unit", dimension.name()));
+            syn::Error::new_spanned(
+                marker,
+                format!(
+                    "`{}` is not a unit trait, expected one of: {}, ExtraUnit<Dimension>, \
+                     or a named dimension's trait with `#[unit_quantity(Quantity)]`",
+                    quote!(#marker).to_string().replace(' ', ""),
+                    traits.join(", ")
+                ),
+            )
+        })
+}
+
+// The names of phys_calc's SI and binary prefixes, `prefix::SI_PREFIXES` and
+// `prefix::BINARY_PREFIXES`.
+const PREFIX_NAMES: [&str; 32] = [
+    "quecto", "ronto", "yocto", "zepto", "atto", "femto", "pico", "nano", "micro", "milli",
+    "centi", "deci", "deca", "hecto", "kilo", "mega", "giga", "tera", "peta", "exa", "zetta",
+    "yotta", "ronna", "quetta", "kibi", "mebi", "gibi", "tebi", "pebi", "exbi", "zebi", "yobi",
+];
+
+// The inherent methods of the number types the literal traits are implemented
+// for (f64, f32 and the integers), from their std docs. Inherent methods win
+// over trait methods, so a literal with one of these names could never be
+// called. Only names without `_` are listed, unit symbols and plurals don't
+// read like `to_degrees`; add new ones here when std gains them.
+const NUMBER_METHODS: [&str; 43] = [
+    "abs", "acos", "acosh", "asin", "asinh", "atan", "atan2", "atanh", "cbrt", "ceil", "clamp",
+    "classify", "copysign", "cos", "cosh", "exp", "exp2", "floor", "fract", "hypot", "ilog",
+    "ilog10", "ilog2", "isqrt", "ln", "log", "log10", "log2", "max", "midpoint", "min", "pow",
+    "powf", "powi", "recip", "round", "signum", "sin", "sinh", "sqrt", "tan", "tanh", "trunc",
+];
+
+// `Kilometer` gets `KilometerLiteral` with `km()` and `kilometers()`, for
+// `5.0.km()`.
+fn literal_trait(
+    krate: &Path,
+    ident: &Ident,
+    quantity: &proc_macro2::TokenStream,
+    symbol: &LitStr,
+    ascii_symbol: &LitStr,
+    plural: &LitStr,
+) -> proc_macro2::TokenStream {
+    let trait_name = Ident::new(&format!("{ident}Literal"), ident.span());
+
+    // Composite symbols read out, `km/h` is `km_per_h()`.
+    let ascii = ascii_symbol.value().replace('/', "_per_");
+    let plural = plural.value().to_lowercase().replace([' ', '-'], "_");
+    let mut methods = vec![];
+    for name in [ascii, plural] {
+        // Symbols like `in` or `'` can't be method names, skip them. So are
+        // ones that would shadow methods numbers already have, `5.min(x)`
+        // must stay `Ord::min`; minutes are still `5.minutes()`.
+        if NUMBER_METHODS.contains(&name.as_str()) {
+            continue;
+        }
+        if let Ok(method) = syn::parse_str::<Ident>(&name) {
+            if !methods.contains(&method) {
+                methods.push(method);
+            }
+        }
+    }
+    // What `q!` calls, one for each form of the symbol.
+    let mut hidden = vec![];
+    for symbol in [symbol.value(), ascii_symbol.value()] {
+        let method = quantity_literal::symbol_method(&symbol, Span::call_site());
+        if !hidden.contains(&method) {
+            hidden.push(method);
+        }
+    }
+
+    let impls = [
+        quote!(f64),
+        quote!(f32),
+        quote!(i32),
+        quote!(i64),
+        quote!(u32),
+        quote!(u64),
+    ]
+    .into_iter()
+    .map(|typ| {
+        quote! {
+            #[allow(non_snake_case)]
+            impl #trait_name for #typ {
+                #(
+                    fn #methods(self) -> #quantity<#ident> {
+                        #quantity::<#ident>::new(self as #krate::math::number)
+                    }
+                )*
+                #(
+                    fn #hidden(self) -> #quantity<#ident> {
+                        #quantity::<#ident>::new(self as #krate::math::number)
+                    }
+                )*
+            }
+        }
+    });
+
+    quote! {
+        #[allow(non_snake_case)]
+        pub trait #trait_name {
+            #(fn #methods(self) -> #quantity<#ident>;)*
+            #(
+                #[doc(hidden)]
+                fn #hidden(self) -> #quantity<#ident>;
+            )*
+        }
+        #(#impls)*
     }
-    .into()
 }
+
 #[proc_macro]
 pub fn impl_derived_conversions(ts: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let input = parse_macro_input!(ts as UnitToDerivedInput);
@@ -118,9 +417,14 @@ pub fn impl_derived_conversions(ts: proc_macro::TokenStream) -> proc_macro::Toke
         type_mappings,
     } = input;
     if type_mappings.is_empty() {
-        panic!("Should have at least 1 type mapping! Ex: Angle: One, A;");
+        return syn::Error::new(
+            name.span(),
+            "expected at least one type mapping, e.g. `Angle: One, A`",
+        )
+        .into_compile_error()
+        .into();
     }
-    let mut hm: HashMap<Ident, (Ident, Ident, Ident)> = vec![
+    let mut hm: HashMap<Ident, (Ident, Ident, Ident)> = [
         ("Length", ("Zero", "None", "L")),
         ("Time", ("Zero", "None", "T")),
         ("Temp", ("Zero", "None", "TMP")),
@@ -149,10 +453,24 @@ pub fn impl_derived_conversions(ts: proc_macro::TokenStream) -> proc_macro::Toke
             exp,
             unit_letter,
         } = map;
-        if !hm.contains_key(typ) {
-            panic!("Key `{}` is not a valid base unit", typ);
+        let Some((_, _, gen_ident)) = hm.get(typ) else {
+            let keys = hm.keys().map(Ident::to_string).collect::<Vec<_>>();
+            return syn::Error::new(
+                typ.span(),
+                format!(
+                    "`{typ}` is not a base dimension, expected one of: {}",
+                    keys.join(", ")
+                ),
+            )
+            .into_compile_error()
+            .into();
+        };
+        if type_mappings.iter().filter(|map| map.typ == *typ).count() > 1 {
+            return syn::Error::new(typ.span(), format!("`{typ}` is mapped more than once"))
+                .into_compile_error()
+                .into();
         }
-        let gen_ident = hm.get(typ).expect("Just checked before").2.clone();
+        let gen_ident = gen_ident.clone();
         hm.insert(typ.clone(), (exp.clone(), unit_letter.clone(), gen_ident));
     }
 
@@ -171,19 +489,12 @@ pub fn impl_derived_conversions(ts: proc_macro::TokenStream) -> proc_macro::Toke
     });
     let generics = quote!(#(#generics),*);
 
-    let mut exps = vec![];
-    let mut units = vec![];
-
-    for (typ, (exp, unit_letter, _)) in hm.iter() {
-        let exp_ident = Ident::new(&format!("{typ}Exp"), Span::call_site());
-        let unit_ident = Ident::new(&format!("{typ}Unit"), Span::call_site());
-        exps.push(quote! {
-            type #exp_ident = #exp;
-        });
-        units.push(quote! {
-            type #unit_ident = #unit_letter;
-        });
-    }
+    let dimension = hm.iter().map(|(typ, (exp, unit_letter, _))| {
+        let power = Ident::new(&format!("{typ}Power"), Span::call_site());
+        quote!(#power<#exp, #unit_letter>)
+    });
+    let dimension = quote!(Derived<#(#dimension),*>);
+
     let mut d_to_u_associated = vec![];
     for (typ, (exp, _, gen_ident)) in hm.iter() {
         let exp_generic = Ident::new(&format!("{typ}Power"), Span::call_site());
@@ -193,9 +504,6 @@ pub fn impl_derived_conversions(ts: proc_macro::TokenStream) -> proc_macro::Toke
         d_to_u_associated.push(line);
     }
 
-    let exps = quote!(#(#exps)*);
-    let units = quote!(#(#units)*);
-
     quote! {
         impl<
                 T: TimeUnit,
@@ -226,22 +534,8 @@ pub fn impl_derived_conversions(ts: proc_macro::TokenStream) -> proc_macro::Toke
         }
 
         impl<#generics> UnitToDerived for #name <#generics_idents> {
-            #exps
-            #units
-            fn to_derived(
-                self,
-            ) -> Derived<
-                LengthPower<Self::LengthExp, Self::LengthUnit>,
-                TimePower<Self::TimeExp, Self::TimeUnit>,
-                TempPower<Self::TempExp, Self::TempUnit>,
-                MassPower<Self::MassExp, Self::MassUnit>,
-                CurrentPower<Self::CurrentExp, Self::CurrentUnit>,
-                LuminousIntensityPower<Self::LuminousIntensityExp, Self::LuminousIntensityUnit>,
-                QuantityPower<Self::QuantityExp, Self::QuantityUnit>,
-                AnglePower<Self::AngleExp, Self::AngleUnit>,
-                SolidAnglePower<Self::SolidAngleExp, Self::SolidAngleUnit>,
-                DigitalInformationPower<Self::DigitalInformationExp, Self::DigitalInformationUnit>,
-            > {
+            type Dimension = #dimension;
+            fn to_derived(self) -> Self::Dimension {
                 Derived {
                     inner: self.inner,
                     types: std::marker::PhantomData,
@@ -261,6 +555,59 @@ impl_unit_to_derived! {
 
 */
 
+// #[unit("μm", ascii = "um", latex = r"\micro\metre", name = "micrometer", plural = "...")]
+struct UnitAttr {
+    symbol: LitStr,
+    ascii: Option<LitStr>,
+    latex: Option<LitStr>,
+    name: Option<LitStr>,
+    plural: Option<LitStr>,
+}
+
+impl Parse for UnitAttr {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let symbol = input.parse()?;
+        let mut attr = UnitAttr {
+            symbol,
+            ascii: None,
+            latex: None,
+            name: None,
+            plural: None,
+        };
+        while !input.is_empty() {
+            let _comma: Token![,] = input.parse()?;
+            if input.is_empty() {
+                break;
+            }
+            let key: Ident = input.parse()?;
+            let _eq: Token![=] = input.parse()?;
+            let value: LitStr = input.parse()?;
+            let slot = match key.to_string().as_str() {
+                "ascii" => &mut attr.ascii,
+                "latex" => &mut attr.latex,
+                "name" => &mut attr.name,
+                "plural" => &mut attr.plural,
+                _ => {
+                    return Err(syn::Error::new(
+                        key.span(),
+                        format!(
+                            "unknown unit symbol form `{key}`, expected `ascii`, `latex`, `name` or `plural`"
+                        ),
+                    ))
+                }
+            };
+            if slot.is_some() {
+                return Err(syn::Error::new(
+                    key.span(),
+                    format!("duplicate `{key}` form"),
+                ));
+            }
+            *slot = Some(value);
+        }
+        Ok(attr)
+    }
+}
+
 #[derive(Clone)]
 struct UnitToDerivedInput {
     name: Ident,
@@ -0,0 +1,401 @@
+use std::ops::Range;
+
+use proc_macro2::{Ident, Literal, Span, TokenStream, TokenTree};
+use quote::{format_ident, quote, quote_spanned};
+use syn::LitStr;
+
+use crate::units::Dimension;
+
+// `q!("9.81 m/s^2")` or `q!(9.81 m/s^2)`. Both forms are turned into text and
+// parsed the same way, remembering which span each piece of text came from.
+//
+// The units themselves are looked up through the literal traits
+// `#[derive(Unit)]` generates, each symbol has a hidden method there (see
+// `symbol_method`). So any unit whose trait is in scope works, phys_calc's own
+// through `literals::*` and downstream ones through their imports, and the
+// type system checks that the units of a compound unit fit together.
+pub fn expand(input: TokenStream) -> syn::Result<TokenStream> {
+    let source = match syn::parse2::<LitStr>(input.clone()) {
+        Ok(lit) => Source::from_lit(&lit),
+        Err(_) => Source::from_tokens(input)?,
+    };
+    let parsed = Parser::new(&source).parse()?;
+    Ok(parsed.to_tokens(&source))
+}
+
+// The hidden literal method of a unit symbol, `km` -> `__unit_km` and
+// `°C` -> `__unit__b0_C`. Anything but ASCII letters and digits is spelled out
+// in hex, so every symbol gets a distinct identifier.
+pub fn symbol_method(symbol: &str, span: Span) -> Ident {
+    let mut name = String::from("__unit_");
+    for c in symbol.chars() {
+        match c.is_ascii_alphanumeric() {
+            true => name.push(c),
+            false => name.push_str(&format!("_{:x}_", c as u32)),
+        }
+    }
+    Ident::new(&name, span)
+}
+
+// Other spellings accepted, as when parsing compound units.
+const ALIASES: [(&str, &str); 1] = [("h", "hr")];
+
+struct Source {
+    text: String,
+    spans: Vec<(Range<usize>, Span)>,
+}
+
+impl Source {
+    fn from_lit(lit: &LitStr) -> Self {
+        let text = lit.value();
+        Source {
+            spans: vec![(0..text.len(), lit.span())],
+            text,
+        }
+    }
+
+    fn from_tokens(input: TokenStream) -> syn::Result<Self> {
+        let mut source = Source {
+            text: String::new(),
+            spans: vec![],
+        };
+        let mut previous_word = false;
+        for token in input {
+            let (text, word) = match &token {
+                TokenTree::Ident(ident) => (ident.to_string(), true),
+                TokenTree::Literal(lit) => (lit.to_string(), true),
+                TokenTree::Punct(punct) => (punct.as_char().to_string(), false),
+                TokenTree::Group(group) => {
+                    return Err(syn::Error::new(
+                        group.span(),
+                        "unexpected group in quantity",
+                    ))
+                }
+            };
+            // `kg m` needs the space, `m/s^2` must stay together so that
+            // symbols like `deg^2` are still recognised.
+            if previous_word && word {
+                source.text.push(' ');
+            }
+            let start = source.text.len();
+            source.text.push_str(&text);
+            source.spans.push((start..source.text.len(), token.span()));
+            previous_word = word;
+        }
+        Ok(source)
+    }
+
+    fn span(&self, at: usize) -> Span {
+        self.spans
+            .iter()
+            .find(|(range, _)| range.contains(&at))
+            .or(self.spans.last())
+            .map(|(_, span)| *span)
+            .unwrap_or_else(Span::call_site)
+    }
+
+    fn error(&self, at: usize, message: impl std::fmt::Display) -> syn::Error {
+        syn::Error::new(self.span(at), message)
+    }
+}
+
+struct Term {
+    symbol: String,
+    exponent: i32,
+    at: usize,
+}
+
+struct Parsed {
+    value: f64,
+    terms: Vec<Term>,
+}
+
+struct Parser<'a> {
+    source: &'a Source,
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn new(source: &'a Source) -> Self {
+        Parser { source, pos: 0 }
+    }
+
+    fn rest(&self) -> &'a str {
+        &self.source.text[self.pos..]
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = self.rest();
+        self.pos += rest.len() - rest.trim_start().len();
+    }
+
+    fn eat(&mut self, c: char) -> bool {
+        if self.rest().starts_with(c) {
+            self.pos += c.len_utf8();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn parse(mut self) -> syn::Result<Parsed> {
+        let value = self.number()?;
+        let mut terms = vec![];
+        loop {
+            self.skip_whitespace();
+            if self.rest().is_empty() {
+                break;
+            }
+            let divide = self.eat('/');
+            if !divide {
+                let _ = self.eat('*') || self.eat('·');
+            }
+            self.skip_whitespace();
+            let (symbol, at) = self.unit()?;
+            let exponent = self.exponent()?;
+            terms.push(Term {
+                symbol,
+                exponent: if divide { -exponent } else { exponent },
+                at,
+            });
+        }
+        if terms.is_empty() {
+            return Err(self
+                .source
+                .error(self.pos, "expected a unit after the number"));
+        }
+        let terms = self.combine(terms)?;
+        Ok(Parsed { value, terms })
+    }
+
+    // Repeated units add up, `m/m` cancels out entirely. Different units of
+    // one dimension are left to the type system, which only knows which
+    // symbols belong together.
+    fn combine(&self, terms: Vec<Term>) -> syn::Result<Vec<Term>> {
+        let mut combined: Vec<Term> = vec![];
+        for term in terms {
+            match combined
+                .iter_mut()
+                .find(|other| other.symbol == term.symbol)
+            {
+                Some(other) => other.exponent += term.exponent,
+                None => combined.push(term),
+            }
+        }
+        combined.retain(|term| term.exponent != 0);
+        if combined.is_empty() {
+            return Err(self
+                .source
+                .error(0, "the units cancel out, use a plain number instead"));
+        }
+        if let Some(term) = combined
+            .iter()
+            .find(|term| exponent_ident(term.exponent).is_none())
+        {
+            return Err(self.source.error(
+                term.at,
+                format!(
+                    "exponent {} of `{}` is out of range (-5 to 5)",
+                    term.exponent, term.symbol
+                ),
+            ));
+        }
+        Ok(combined)
+    }
+
+    fn number(&mut self) -> syn::Result<f64> {
+        self.skip_whitespace();
+        let start = self.pos;
+        let negative = self.eat('-');
+        let bytes = self.rest().as_bytes();
+        let digits = |mut i: usize| {
+            while i < bytes.len() && bytes[i].is_ascii_digit() {
+                i += 1;
+            }
+            i
+        };
+        let mut len = digits(0);
+        if len < bytes.len() && bytes[len] == b'.' {
+            len = digits(len + 1);
+        }
+        if len < bytes.len() && (bytes[len] == b'e' || bytes[len] == b'E') {
+            let mut exp = len + 1;
+            if exp < bytes.len() && (bytes[exp] == b'+' || bytes[exp] == b'-') {
+                exp += 1;
+            }
+            if exp < bytes.len() && bytes[exp].is_ascii_digit() {
+                len = digits(exp);
+            }
+        }
+        let text = &self.rest()[..len];
+        let value = text
+            .parse::<f64>()
+            .map_err(|_| self.source.error(start, "expected a number"))?;
+        self.pos += len;
+        Ok(if negative { -value } else { value })
+    }
+
+    // A symbol runs up to the next operator or exponent, `m/s^2` is `m`, `s`.
+    fn unit(&mut self) -> syn::Result<(String, usize)> {
+        let at = self.pos;
+        let symbol: String = self
+            .rest()
+            .chars()
+            .take_while(|&c| {
+                !c.is_whitespace() && !"/*·^⁻".contains(c) && superscript_digit(c).is_none()
+            })
+            .collect();
+        if symbol.is_empty() {
+            return Err(self.source.error(at, "expected a unit"));
+        }
+        self.pos += symbol.len();
+        let symbol = match ALIASES.iter().find(|(alias, _)| *alias == symbol) {
+            Some((_, unit)) => unit.to_string(),
+            None => symbol,
+        };
+        Ok((symbol, at))
+    }
+
+    // `^2`, `^-1` or superscripts (`²`, `⁻¹`), as written by the formatters.
+    fn exponent(&mut self) -> syn::Result<i32> {
+        let save = self.pos;
+        self.skip_whitespace();
+        if self.eat('^') {
+            self.skip_whitespace();
+            let at = self.pos;
+            let negative = self.eat('-');
+            let len = self.rest().len()
+                - self
+                    .rest()
+                    .trim_start_matches(|c: char| c.is_ascii_digit())
+                    .len();
+            let exponent = self.rest()[..len]
+                .parse::<i32>()
+                .map_err(|_| self.source.error(at, "expected an integer exponent"))?;
+            self.pos += len;
+            return Ok(if negative { -exponent } else { exponent });
+        }
+        self.pos = save;
+
+        let negative = self.eat('⁻');
+        let mut exponent = None;
+        while let Some(digit) = self.rest().chars().next().and_then(superscript_digit) {
+            exponent = Some(exponent.unwrap_or(0) * 10 + digit);
+            self.pos += self.rest().chars().next().map_or(0, char::len_utf8);
+        }
+        match (exponent, negative) {
+            (Some(exponent), true) => Ok(-exponent),
+            (Some(exponent), false) => Ok(exponent),
+            (None, true) => Err(self
+                .source
+                .error(self.pos, "expected a superscript exponent")),
+            (None, false) => Ok(1),
+        }
+    }
+}
+
+fn superscript_digit(c: char) -> Option<i32> {
+    "⁰¹²³⁴⁵⁶⁷⁸⁹"
+        .chars()
+        .position(|digit| digit == c)
+        .map(|digit| digit as i32)
+}
+
+fn exponent_ident(exponent: i32) -> Option<&'static str> {
+    let names = [
+        "NegFive", "NegFour", "NegThree", "NegTwo", "NegOne", "Zero", "One", "Two", "Three",
+        "Four", "Five",
+    ];
+    names.get(usize::try_from(exponent + 5).ok()?).copied()
+}
+
+impl Parsed {
+    fn to_tokens(&self, source: &Source) -> TokenStream {
+        let krate = quote!(::phys_calc);
+        let value = Literal::f64_suffixed(self.value.abs());
+        let value = match self.value < 0. {
+            true => quote!((-#value)),
+            false => quote!(#value),
+        };
+        // A symbol no literal trait knows falls back to the method here, which
+        // only needs `&self` so any real one is picked first. It returns `()`,
+        // and the bound on `known` then names the symbol in the error.
+        let literal = |term: &Term, value: &TokenStream| {
+            let span = source.span(term.at);
+            let method = symbol_method(&term.symbol, span);
+            let message = format!("unknown unit `{}`", term.symbol);
+            quote_spanned! {span=> {
+                #[diagnostic::on_unimplemented(
+                    message = #message,
+                    label = "no unit with this symbol is in scope",
+                    note = "units from other crates need their literal trait imported"
+                )]
+                trait Known {}
+                impl<T: #krate::math_helpers::UnitToDerived> Known for T {}
+                #[allow(dead_code, non_snake_case)]
+                trait Unknown {
+                    fn #method(&self) {}
+                }
+                impl Unknown for f64 {}
+                fn known<T: Known>(quantity: T) -> T {
+                    quantity
+                }
+                known(#value.#method())
+            }}
+        };
+
+        // A single unit to the first power is that unit's quantity,
+        // `Length<Kilometer>` or `Volume<Liter>`.
+        if let [term @ Term { exponent: 1, .. }] = self.terms.as_slice() {
+            let literal = literal(term, &value);
+            return quote!({
+                #[allow(unused_imports)]
+                use #krate::literals::*;
+                #literal
+            });
+        }
+
+        // Otherwise each unit becomes a `Derived` and they are multiplied
+        // together, the value going with the first unit that is multiplied.
+        let mut factors = self
+            .terms
+            .iter()
+            .flat_map(|term| (0..term.exponent.abs()).map(move |_| term))
+            .collect::<Vec<_>>();
+        factors.sort_by_key(|term| term.exponent < 0);
+        let compound_term = |term: &Term, value: &TokenStream| {
+            let span = source.span(term.at);
+            let literal = literal(term, value);
+            quote_spanned!(span=> #krate::math_helpers::compound_term(#literal))
+        };
+        let (first, rest) = match factors.split_first() {
+            Some((first, rest)) if first.exponent > 0 => (compound_term(first, &value), rest),
+            // Only divisions, `q!(5 /s)`, start from the plain number.
+            _ => {
+                let powers = Dimension::ALL.iter().map(|dimension| {
+                    let power = format_ident!("{}Power", dimension.name());
+                    quote!(#krate::math_helpers::#power<#krate::math::Zero, #krate::unit::None>)
+                });
+                let number = quote!(#krate::math_helpers::Derived::<#(#powers),*>::new(#value));
+                (number, factors.as_slice())
+            }
+        };
+        let steps = rest.iter().map(|term| {
+            let op = match term.exponent < 0 {
+                true => quote!(#krate::math_helpers::Per),
+                false => quote!(#krate::math_helpers::Times),
+            };
+            let factor = compound_term(term, &quote!(1f64));
+            quote_spanned! {source.span(term.at)=>
+                let quantity = #krate::math_helpers::DimensionOp::<_, #op>::apply(quantity, #factor);
+            }
+        });
+        quote!({
+            #[allow(unused_imports)]
+            use #krate::literals::*;
+            let quantity = #first;
+            #(#steps)*
+            quantity
+        })
+    }
+}
@@ -0,0 +1,78 @@
+// The base dimensions, mirroring `phys_calc/src/base`. A proc macro can't see
+// the types of the crate it expands into, so `#[derive(Unit)]` and `q!` find
+// their modules and `Derived` slots here.
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Dimension {
+    Length,
+    Time,
+    Temp,
+    Mass,
+    Current,
+    LuminousIntensity,
+    Quantity,
+    Angle,
+    SolidAngle,
+    DigitalInformation,
+}
+
+impl Dimension {
+    // In the order of `Derived`'s type parameters.
+    pub const ALL: [Dimension; 10] = [
+        Dimension::Length,
+        Dimension::Time,
+        Dimension::Temp,
+        Dimension::Mass,
+        Dimension::Current,
+        Dimension::LuminousIntensity,
+        Dimension::Quantity,
+        Dimension::Angle,
+        Dimension::SolidAngle,
+        Dimension::DigitalInformation,
+    ];
+
+    pub fn quantity(self) -> &'static str {
+        match self {
+            Dimension::Temp => "Temperature",
+            dimension => dimension.name(),
+        }
+    }
+
+    pub fn module(self) -> &'static str {
+        match self {
+            Dimension::Length => "length",
+            Dimension::Time => "time",
+            Dimension::Temp => "temperature",
+            Dimension::Mass => "mass",
+            Dimension::Current => "current",
+            Dimension::LuminousIntensity => "luminous_intensity",
+            Dimension::Quantity => "quantity",
+            Dimension::Angle => "angle",
+            Dimension::SolidAngle => "solid_angle",
+            Dimension::DigitalInformation => "digital_information",
+        }
+    }
+
+    // `LengthUnit` -> `Length`, and so on.
+    pub fn from_unit_trait(name: &str) -> Option<Dimension> {
+        Dimension::ALL
+            .into_iter()
+            .find(|dimension| name.strip_suffix("Unit") == Some(dimension.name()))
+    }
+
+    // The prefix of the dimension's `...Power` slot in `Derived`.
+    pub fn name(self) -> &'static str {
+        match self {
+            Dimension::Length => "Length",
+            Dimension::Time => "Time",
+            Dimension::Temp => "Temp",
+            Dimension::Mass => "Mass",
+            Dimension::Current => "Current",
+            Dimension::LuminousIntensity => "LuminousIntensity",
+            Dimension::Quantity => "Quantity",
+            Dimension::Angle => "Angle",
+            Dimension::SolidAngle => "SolidAngle",
+            Dimension::DigitalInformation => "DigitalInformation",
+        }
+    }
+}
@@ -0,0 +1,5 @@
+use phys_calc_macros::impl_derived_conversions;
+
+impl_derived_conversions!(Length<L: LengthUnit>,);
+
+fn main() {}
@@ -0,0 +1,9 @@
+use phys_calc_macros::Unit;
+
+#[derive(Unit)]
+#[multiplier(1.)]
+#[unit("m", latex = r"\metre", name = "meter", name = "metre")]
+#[unit_impl(LengthUnit)]
+struct Meter;
+
+fn main() {}
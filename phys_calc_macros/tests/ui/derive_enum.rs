@@ -0,0 +1,9 @@
+use phys_calc_macros::Unit;
+
+#[derive(Unit)]
+#[multiplier(1.)]
+#[unit("m", latex = r"\metre", name = "meter")]
+#[unit_impl(LengthUnit)]
+enum Meter {}
+
+fn main() {}
@@ -0,0 +1,8 @@
+use phys_calc_macros::Unit;
+
+#[derive(Unit)]
+#[unit("m", latex = r"\metre", name = "meter")]
+#[unit_impl(LengthUnit)]
+struct Meter;
+
+fn main() {}
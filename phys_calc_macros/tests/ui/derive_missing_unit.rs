@@ -0,0 +1,8 @@
+use phys_calc_macros::Unit;
+
+#[derive(Unit)]
+#[multiplier(1.)]
+#[unit_impl(LengthUnit)]
+struct Meter;
+
+fn main() {}
@@ -0,0 +1,8 @@
+use phys_calc_macros::Unit;
+
+#[derive(Unit)]
+#[multiplier(1.)]
+#[unit("m", latex = r"\metre", name = "meter")]
+struct Meter;
+
+fn main() {}
@@ -0,0 +1,9 @@
+use phys_calc_macros::Unit;
+
+#[derive(Unit)]
+#[multiplier(1e-6)]
+#[unit("μm", latex = r"\micro\metre", name = "micrometer")]
+#[unit_impl(LengthUnit)]
+struct Micrometer;
+
+fn main() {}
@@ -0,0 +1,5 @@
+use phys_calc_macros::q;
+
+fn main() {
+    let _ = q!(3 m^9);
+}